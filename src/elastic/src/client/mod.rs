@@ -143,8 +143,17 @@ They're exposed as methods on the `Client`:
 Client method                                                 | Elasticsearch API                  | Raw request type                                        | Response type
 ------------------------------------------------------------- | ---------------------------------- | ------------------------------------------------------- | ------------------------------------
 [`search`][Client.search]                                     | [Search][docs-search]              | [`SearchRequest`][SearchRequest]                        | [`SearchResponse`][SearchResponse]
+[`search_template`][Client.search_template]                   | [Search Template][docs-search-template] | [`SearchTemplateRequest`][SearchTemplateRequest] | [`SearchResponse`][SearchResponse]
+[`validate_query`][Client.validate_query]                     | [Validate API][docs-validate]      | [`IndicesValidateQueryRequest`][IndicesValidateQueryRequest] | [`ValidateQueryResponse`][ValidateQueryResponse]
 [`bulk`][Client.bulk]                                         | [Bulk][docs-bulk]                  | [`BulkRequest`][BulkRequest]                            | [`BulkResponse`][BulkResponse]
 [`ping`][Client.ping]                                         | -                                  | [`PingRequest`][PingRequest]                            | [`PingResponse`][PingResponse]
+[`tasks_list`][Client.tasks_list]                             | [Task Management][docs-tasks]      | [`TasksListRequest`][TasksListRequest]                  | [`TasksListResponse`][TasksListResponse]
+[`nodes_hot_threads`][Client.nodes_hot_threads]               | [Hot Threads][docs-hot-threads]    | [`NodesHotThreadsRequest`][NodesHotThreadsRequest]      | [`NodesHotThreadsResponse`][NodesHotThreadsResponse]
+[`cat_nodes`][Client.cat_nodes]                               | [Cat Nodes][docs-cat-nodes]        | [`CatNodesRequest`][CatNodesRequest]                    | [`CatNodesResponse`][CatNodesResponse]
+[`cat_shards`][Client.cat_shards]                             | [Cat Shards][docs-cat-shards]      | [`CatShardsRequest`][CatShardsRequest]                  | [`CatShardsResponse`][CatShardsResponse]
+[`snapshot_status`][Client.snapshot_status]                   | [Snapshot Status][docs-snapshot-status] | [`SnapshotStatusRequest`][SnapshotStatusRequest]   | [`SnapshotStatusResponse`][SnapshotStatusResponse]
+[`cluster_settings`][Client.cluster_settings]                 | [Cluster Settings][docs-cluster-settings] | [`ClusterGetSettingsRequest`][ClusterGetSettingsRequest] | [`ClusterSettingsResponse`][ClusterSettingsResponse]
+[`cluster_put_settings`][Client.cluster_put_settings]         | [Cluster Settings][docs-cluster-settings] | [`ClusterPutSettingsRequest`][ClusterPutSettingsRequest] | [`ClusterSettingsResponse`][ClusterSettingsResponse]
 [`document.search`][Client.document.search]                   | [Search][docs-search]              | [`SearchRequest`][SearchRequest]                        | [`SearchResponse`][SearchResponse]
 [`document.get`][Client.document.get]                         | [Get Document][docs-get]           | [`GetRequest`][GetRequest]                              | [`GetResponse`][GetResponse]
 [`document.index`][Client.document.index]                     | [Index Document][docs-index]       | [`IndexRequest`][IndexRequest]                          | [`IndexResponse`][IndexResponse]
@@ -480,6 +489,8 @@ For more details see the [`responses`][responses-mod] module.
 
 [docs-bulk]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
 [docs-search]: http://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html
+[docs-search-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html
+[docs-validate]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html
 [docs-get]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html
 [docs-update]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update.html
 [docs-delete]: http://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete.html
@@ -490,6 +501,12 @@ For more details see the [`responses`][responses-mod] module.
 [docs-open-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-open-close.html
 [docs-index-exists]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-exists.html
 [docs-delete-index]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-delete-index.html
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html
+[docs-hot-threads]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-nodes-hot-threads.html
+[docs-cat-nodes]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-nodes.html
+[docs-cat-shards]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-shards.html
+[docs-snapshot-status]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot_status
+[docs-cluster-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-update-settings.html
 
 [tokio]: https://tokio.rs
 
@@ -502,6 +519,8 @@ For more details see the [`responses`][responses-mod] module.
 [Client.request]: struct.Client.html#method.request
 [Client.bulk]: struct.Client.html#bulk-request
 [Client.search]: struct.Client.html#search-request
+[Client.search_template]: struct.Client.html#search-template-request
+[Client.validate_query]: struct.Client.html#validate-query-request
 [Client.document.search]: struct.DocumentClient.html#search-request
 [Client.document.get]: struct.DocumentClient.html#get-document-request
 [Client.document.update]: struct.DocumentClient.html#update-document-request
@@ -514,11 +533,20 @@ For more details see the [`responses`][responses-mod] module.
 [Client.index.delete]: struct.IndexClient.html#delete-index-request
 [Client.index.exists]: struct.IndexClient.html#index-exists-request
 [Client.ping]: struct.Client.html#ping-request
+[Client.tasks_list]: struct.Client.html#task-listing-request
+[Client.nodes_hot_threads]: struct.Client.html#nodes-hot-threads-request
+[Client.cat_nodes]: struct.Client.html#cat-nodes-request
+[Client.cat_shards]: struct.Client.html#cat-shards-request
+[Client.snapshot_status]: struct.Client.html#snapshot-status-request
+[Client.cluster_settings]: struct.Client.html#cluster-settings-request
+[Client.cluster_put_settings]: struct.Client.html#cluster-update-settings-request
 
 [RequestBuilder]: requests/struct.RequestBuilder.html
 [RequestBuilder.params]: requests/struct.RequestBuilder.html#method.params
 [RawRequestBuilder]: requests/type.RawRequestBuilder.html
 [SearchRequest]: requests/endpoints/struct.SearchRequest.html
+[SearchTemplateRequest]: requests/endpoints/struct.SearchTemplateRequest.html
+[IndicesValidateQueryRequest]: requests/endpoints/struct.IndicesValidateQueryRequest.html
 [BulkRequest]: requests/endpoints/struct.BulkRequest.html
 [GetRequest]: requests/endpoints/struct.GetRequest.html
 [UpdateRequest]: requests/endpoints/struct.UpdateRequest.html
@@ -531,6 +559,13 @@ For more details see the [`responses`][responses-mod] module.
 [IndicesDeleteRequest]: requests/endpoints/struct.IndicesDeleteRequest.html
 [IndicesExistsRequest]: requests/endpoints/struct.IndicesExistsRequest.html
 [PingRequest]: requests/endpoints/struct.PingRequest.html
+[TasksListRequest]: requests/endpoints/struct.TasksListRequest.html
+[NodesHotThreadsRequest]: requests/endpoints/struct.NodesHotThreadsRequest.html
+[CatNodesRequest]: requests/endpoints/struct.CatNodesRequest.html
+[CatShardsRequest]: requests/endpoints/struct.CatShardsRequest.html
+[SnapshotStatusRequest]: requests/endpoints/struct.SnapshotStatusRequest.html
+[ClusterGetSettingsRequest]: requests/endpoints/struct.ClusterGetSettingsRequest.html
+[ClusterPutSettingsRequest]: requests/endpoints/struct.ClusterPutSettingsRequest.html
 
 [responses-mod]: responses/index.html
 [SyncResponseBuilder]: responses/struct.SyncResponseBuilder.html
@@ -540,6 +575,7 @@ For more details see the [`responses`][responses-mod] module.
 [AsyncResponseBuilder.into_response]: responses/struct.AsyncResponseBuilder.html#method.into_response
 [AsyncResponseBuilder.into_raw]: responses/struct.AsyncResponseBuilder.html#method.into_raw
 [SearchResponse]: responses/struct.SearchResponse.html
+[ValidateQueryResponse]: responses/struct.ValidateQueryResponse.html
 [BulkResponse]: responses/struct.BulkResponse.html
 [GetResponse]: responses/struct.GetResponse.html
 [UpdateResponse]: responses/struct.UpdateResponse.html
@@ -547,6 +583,12 @@ For more details see the [`responses`][responses-mod] module.
 [IndexResponse]: responses/struct.IndexResponse.html
 [IndicesExistsResponse]: responses/struct.IndicesExistsResponse.html
 [PingResponse]: responses/struct.PingResponse.html
+[TasksListResponse]: responses/struct.TasksListResponse.html
+[NodesHotThreadsResponse]: responses/struct.NodesHotThreadsResponse.html
+[CatNodesResponse]: responses/struct.CatNodesResponse.html
+[CatShardsResponse]: responses/struct.CatShardsResponse.html
+[SnapshotStatusResponse]: responses/struct.SnapshotStatusResponse.html
+[ClusterSettingsResponse]: responses/struct.ClusterSettingsResponse.html
 [CommandResponse]: responses/struct.CommandResponse.html
 [SyncHttpResponse]: responses/struct.SyncHttpResponse.html
 [AsyncHttpResponse]: responses/struct.AsyncHttpResponse.html
@@ -56,6 +56,11 @@ struct BulkHeader {
         skip_serializing_if = "Option::is_none"
     )]
     id: Option<Id<'static>>,
+    #[serde(
+        serialize_with = "serialize_param",
+        skip_serializing_if = "Option::is_none"
+    )]
+    routing: Option<String>,
 }
 
 fn serialize_param<S, T>(field: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
@@ -79,7 +84,7 @@ impl<TParams> BulkOperation<Script<TParams>> {
     {
         let inner = self
             .inner
-            .map(|script| builder(ScriptBuilder::from_script(script)).build());
+            .map(|script| Script::from(builder(script.into_builder())));
 
         BulkOperation {
             action: self.action,
@@ -116,6 +121,16 @@ impl<TValue> BulkOperation<TValue> {
         self.header.id = Some(id.into());
         self
     }
+
+    /**
+    Set the routing for this bulk operation.
+
+    Documents in indices with custom routing, or child documents with a parent, need their routing set to be sent to the right shard.
+    */
+    pub fn routing(mut self, routing: impl Into<String>) -> Self {
+        self.header.routing = Some(routing.into());
+        self
+    }
 }
 
 impl<TDocument> BulkOperation<TDocument>
@@ -196,6 +211,7 @@ where
                 index: Some(Index::from(doc.index().into_owned())),
                 ty: Some(Type::from(doc.ty().into_owned())),
                 id: doc.partial_id().map(|id| Id::from(id.into_owned())),
+                routing: None,
             },
             inner: Some(Doc::value(doc)),
         }
@@ -208,6 +224,7 @@ where
                 index: Some(Index::from(doc.index().into_owned())),
                 ty: Some(Type::from(doc.ty().into_owned())),
                 id: doc.partial_id().map(|id| Id::from(id.into_owned())),
+                routing: None,
             },
             inner: Some(Doc::value(doc)),
         }
@@ -228,6 +245,7 @@ where
                 index: TDocument::partial_static_index().map(Into::into),
                 ty: TDocument::partial_static_ty().map(Into::into),
                 id: Some(id.into()),
+                routing: None,
             },
             inner: Some(Script::new(script)),
         }
@@ -250,6 +268,7 @@ where
                 index: TDocument::partial_static_index().map(Into::into),
                 ty: TDocument::partial_static_ty().map(Into::into),
                 id: Some(id.into()),
+                routing: None,
             },
             inner: Some(Script::new(script)),
         }
@@ -263,6 +282,7 @@ where
                 index: Some(Index::from(doc.index().into_owned())),
                 ty: Some(Type::from(doc.ty().into_owned())),
                 id: doc.partial_id().map(|id| Id::from(id.into_owned())),
+                routing: None,
             },
             inner: Some(Doc::value(doc)),
         }
@@ -278,6 +298,7 @@ where
                 index: TDocument::partial_static_index().map(Into::into),
                 ty: TDocument::partial_static_ty().map(Into::into),
                 id: Some(id.into()),
+                routing: None,
             },
             inner: None,
         }
@@ -307,6 +328,7 @@ impl BulkRawOperation {
                 index: None,
                 ty: None,
                 id: None,
+                routing: None,
             },
             inner: Some(Doc::value(doc)),
         }
@@ -319,12 +341,16 @@ impl BulkRawOperation {
                 index: None,
                 ty: None,
                 id: None,
+                routing: None,
             },
             inner: Some(Doc::value(doc)),
         }
     }
 
-    pub fn update_script<TScript>(self, script: TScript) -> BulkOperation<Script<DefaultParams>>
+    pub fn update_script<TScript>(
+        self,
+        script: TScript,
+    ) -> BulkOperation<Script<DefaultParams>>
     where
         TScript: ToString,
     {
@@ -334,6 +360,7 @@ impl BulkRawOperation {
                 index: None,
                 ty: None,
                 id: None,
+                routing: None,
             },
             inner: Some(Script::new(script)),
         }
@@ -354,6 +381,7 @@ impl BulkRawOperation {
                 index: None,
                 ty: None,
                 id: None,
+                routing: None,
             },
             inner: Some(Script::new(script)),
         }
@@ -367,6 +395,7 @@ impl BulkRawOperation {
                 index: None,
                 ty: None,
                 id: None,
+                routing: None,
             },
             inner: Some(Doc::value(doc)),
         }
@@ -379,6 +408,7 @@ impl BulkRawOperation {
                 index: None,
                 ty: None,
                 id: None,
+                routing: None,
             },
             inner: None,
         }
@@ -388,3 +418,45 @@ impl BulkRawOperation {
 pub fn bulk_raw() -> BulkRawOperation {
     BulkRawOperation::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+
+    use super::*;
+
+    fn write_header<TValue>(op: BulkOperation<TValue>) -> String
+    where
+        TValue: Serialize,
+    {
+        let mut buf = Vec::new();
+        op.write(&mut buf).unwrap();
+
+        let written = str::from_utf8(&buf).unwrap();
+        written.lines().next().unwrap().to_owned()
+    }
+
+    #[test]
+    fn write_header_with_routing() {
+        let op = bulk_raw()
+            .delete()
+            .index("myindex")
+            .id("1")
+            .routing("key");
+
+        assert_eq!(
+            r#"{"delete":{"_index":"myindex","_id":"1","routing":"key"}}"#,
+            write_header(op)
+        );
+    }
+
+    #[test]
+    fn write_header_without_routing() {
+        let op = bulk_raw().delete().index("myindex").id("1");
+
+        assert_eq!(
+            r#"{"delete":{"_index":"myindex","_id":"1"}}"#,
+            write_header(op)
+        );
+    }
+}
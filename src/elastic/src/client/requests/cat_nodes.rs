@@ -0,0 +1,221 @@
+/*!
+Builders for [cat nodes requests][docs-cat-nodes].
+
+[docs-cat-nodes]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cat-nodes.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+
+use client::requests::endpoints::CatNodesRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::RequestBuilder;
+use client::responses::CatNodesResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    Error,
+    Result,
+};
+
+/**
+A [cat nodes request][docs-cat-nodes] builder that can be configured before sending.
+
+Call [`Client.cat_nodes`][Client.cat_nodes] to get a `CatNodesRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cat-nodes]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cat-nodes.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cat_nodes]: ../../struct.Client.html#cat-nodes-request
+*/
+pub type CatNodesRequestBuilder<TSender> = RequestBuilder<TSender, CatNodesRequestInner>;
+
+#[doc(hidden)]
+pub struct CatNodesRequestInner;
+
+/**
+# Cat nodes request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`CatNodesRequestBuilder`][CatNodesRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request is sent with `format=json` so each node is returned as a typed row.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Print the heap usage of each node in the cluster:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cat_nodes().send()?;
+
+    for node in response.iter() {
+        println!("{:?}: {:?}%", node.name(), node.heap_percent());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [CatNodesRequestBuilder]: requests/cat_nodes/type.CatNodesRequestBuilder.html
+    [send-sync]: requests/cat_nodes/type.CatNodesRequestBuilder.html#send-synchronously
+    [send-async]: requests/cat_nodes/type.CatNodesRequestBuilder.html#send-asynchronously
+    */
+    pub fn cat_nodes(&self) -> CatNodesRequestBuilder<TSender> {
+        RequestBuilder::initial(self.clone(), CatNodesRequestInner)
+    }
+}
+
+impl CatNodesRequestInner {
+    fn into_request(self) -> CatNodesRequest<'static> {
+        CatNodesRequest::new()
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl CatNodesRequestBuilder<SyncSender> {
+    /**
+    Send a `CatNodesRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Print the load average of each node in the cluster:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cat_nodes().send()?;
+
+    for node in response.iter() {
+        println!("{:?}: {:?}", node.name(), node.load_1m());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CatNodesResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .params_fluent(|p| p.url_param("format", "json"))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl CatNodesRequestBuilder<AsyncSender> {
+    /**
+    Send a `CatNodesRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cat nodes response.
+
+    # Examples
+
+    Print the load average of each node in the cluster:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.cat_nodes().send();
+
+    future.and_then(|response| {
+        for node in response.iter() {
+            println!("{:?}: {:?}", node.name(), node.load_1m());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .params_fluent(|p| p.url_param("format", "json"))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CatNodesResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CatNodesResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CatNodesResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cat_nodes().inner.into_request();
+
+        assert_eq!("/_cat/nodes", req.url.as_ref());
+    }
+}
@@ -0,0 +1,258 @@
+/*!
+Builders for [cat shards requests][docs-cat-shards].
+
+[docs-cat-shards]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cat-shards.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+
+use client::requests::endpoints::CatShardsRequest;
+use client::requests::params::Index;
+use client::requests::raw::RawRequestInner;
+use client::requests::RequestBuilder;
+use client::responses::CatShardsResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    Error,
+    Result,
+};
+
+/**
+A [cat shards request][docs-cat-shards] builder that can be configured before sending.
+
+Call [`Client.cat_shards`][Client.cat_shards] to get a `CatShardsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cat-shards]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cat-shards.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cat_shards]: ../../struct.Client.html#cat-shards-request
+*/
+pub type CatShardsRequestBuilder<TSender> = RequestBuilder<TSender, CatShardsRequestInner>;
+
+#[doc(hidden)]
+pub struct CatShardsRequestInner {
+    index: Option<Index<'static>>,
+}
+
+/**
+# Cat shards request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`CatShardsRequestBuilder`][CatShardsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request is sent with `format=json` and `bytes=b` so each shard is returned as a typed row with its size in bytes.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Find the shards that aren't assigned to any node:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cat_shards().send()?;
+
+    for shard in response.unassigned() {
+        println!("{}: {:?}", shard.index(), shard.shard());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [CatShardsRequestBuilder]: requests/cat_shards/type.CatShardsRequestBuilder.html
+    [builder-methods]: requests/cat_shards/type.CatShardsRequestBuilder.html#builder-methods
+    [send-sync]: requests/cat_shards/type.CatShardsRequestBuilder.html#send-synchronously
+    [send-async]: requests/cat_shards/type.CatShardsRequestBuilder.html#send-asynchronously
+    */
+    pub fn cat_shards(&self) -> CatShardsRequestBuilder<TSender> {
+        RequestBuilder::initial(self.clone(), CatShardsRequestInner { index: None })
+    }
+}
+
+impl CatShardsRequestInner {
+    fn into_request(self) -> CatShardsRequest<'static> {
+        match self.index {
+            Some(index) => CatShardsRequest::for_index(index),
+            None => CatShardsRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `CatShardsRequestBuilder` before sending it.
+*/
+impl<TSender> CatShardsRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices to list shards for.
+
+    If no index is specified then shards for all indices are listed.
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = Some(index.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl CatShardsRequestBuilder<SyncSender> {
+    /**
+    Send a `CatShardsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Print the size of each shard in an index called `myindex`:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cat_shards().index("myindex").send()?;
+
+    for shard in response.iter() {
+        println!("{:?} on {:?}: {:?} bytes", shard.shard(), shard.node(), shard.store());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CatShardsResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .params_fluent(|p| p.url_param("format", "json").url_param("bytes", "b"))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl CatShardsRequestBuilder<AsyncSender> {
+    /**
+    Send a `CatShardsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cat shards response.
+
+    # Examples
+
+    Print the size of each shard in an index called `myindex`:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.cat_shards().index("myindex").send();
+
+    future.and_then(|response| {
+        for shard in response.iter() {
+            println!("{:?} on {:?}: {:?} bytes", shard.shard(), shard.node(), shard.store());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .params_fluent(|p| p.url_param("format", "json").url_param("bytes", "b"))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = CatShardsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CatShardsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CatShardsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cat_shards().inner.into_request();
+
+        assert_eq!("/_cat/shards", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cat_shards().index("new-idx").inner.into_request();
+
+        assert_eq!("/_cat/shards/new-idx", req.url.as_ref());
+    }
+}
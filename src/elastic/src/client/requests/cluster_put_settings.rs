@@ -0,0 +1,348 @@
+/*!
+Builders for [cluster update settings requests][docs-cluster-settings].
+
+[docs-cluster-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-update-settings.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
+
+use client::requests::endpoints::ClusterPutSettingsRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::RequestBuilder;
+use client::responses::ClusterSettingsResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    self,
+    Error,
+    Result,
+};
+
+/**
+A [cluster update settings request][docs-cluster-settings] builder that can be configured before sending.
+
+Call [`Client.cluster_put_settings`][Client.cluster_put_settings] to get a `ClusterPutSettingsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cluster-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-update-settings.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cluster_put_settings]: ../../struct.Client.html#cluster-update-settings-request
+*/
+pub type ClusterPutSettingsRequestBuilder<TSender> =
+    RequestBuilder<TSender, ClusterPutSettingsRequestInner>;
+
+#[doc(hidden)]
+pub struct ClusterPutSettingsRequestInner {
+    persistent: Map<String, Value>,
+    transient: Map<String, Value>,
+}
+
+#[derive(Serialize)]
+struct ClusterSettingsBody<'a> {
+    persistent: &'a Map<String, Value>,
+    transient: &'a Map<String, Value>,
+}
+
+/**
+# Cluster update settings request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ClusterPutSettingsRequestBuilder`][ClusterPutSettingsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The response contains the settings that were changed by the request.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Disable shard allocation before a rolling restart:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster_put_settings()
+                         .transient("cluster.routing.allocation.enable", "primaries")
+                         .send()?;
+
+    assert_eq!(Some(true), response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [ClusterPutSettingsRequestBuilder]: requests/cluster_put_settings/type.ClusterPutSettingsRequestBuilder.html
+    [builder-methods]: requests/cluster_put_settings/type.ClusterPutSettingsRequestBuilder.html#builder-methods
+    [send-sync]: requests/cluster_put_settings/type.ClusterPutSettingsRequestBuilder.html#send-synchronously
+    [send-async]: requests/cluster_put_settings/type.ClusterPutSettingsRequestBuilder.html#send-asynchronously
+    */
+    pub fn cluster_put_settings(&self) -> ClusterPutSettingsRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.clone(),
+            ClusterPutSettingsRequestInner {
+                persistent: Map::new(),
+                transient: Map::new(),
+            },
+        )
+    }
+}
+
+impl ClusterPutSettingsRequestInner {
+    fn into_request(self) -> Result<ClusterPutSettingsRequest<'static, Vec<u8>>> {
+        if self.persistent.is_empty() && self.transient.is_empty() {
+            return Err(error::request(error::message(
+                "at least one persistent or transient setting must be set",
+            )));
+        }
+
+        let body = serde_json::to_vec(&ClusterSettingsBody {
+            persistent: &self.persistent,
+            transient: &self.transient,
+        })
+        .map_err(error::request)?;
+
+        Ok(ClusterPutSettingsRequest::new(body))
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ClusterPutSettingsRequestBuilder` before sending it.
+*/
+impl<TSender> ClusterPutSettingsRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set a persistent setting.
+
+    At least one persistent or transient setting must be set before the request is sent.
+
+    Persistent settings survive a full cluster restart.
+    Set a value of `Value::Null` to reset the setting to its default.
+    */
+    pub fn persistent(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.inner.persistent.insert(key.into(), value.into());
+        self
+    }
+
+    /**
+    Set a transient setting.
+
+    Transient settings are cleared on a full cluster restart and take precedence over persistent ones.
+    Set a value of `Value::Null` to reset the setting to its default.
+    */
+    pub fn transient(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.inner.transient.insert(key.into(), value.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ClusterPutSettingsRequestBuilder<SyncSender> {
+    /**
+    Send a `ClusterPutSettingsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Re-enable shard allocation after a rolling restart:
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster_put_settings()
+                         .transient("cluster.routing.allocation.enable", Value::Null)
+                         .send()?;
+
+    assert_eq!(Some(true), response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ClusterSettingsResponse> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .params_fluent(|p| p.url_param("flat_settings", true))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ClusterPutSettingsRequestBuilder<AsyncSender> {
+    /**
+    Send a `ClusterPutSettingsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cluster settings response.
+
+    # Examples
+
+    Disable shard allocation before a rolling restart:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.cluster_put_settings()
+                       .transient("cluster.routing.allocation.enable", "primaries")
+                       .send();
+
+    future.and_then(|response| {
+        assert_eq!(Some(true), response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .params_fluent(|p| p.url_param("flat_settings", true))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = ClusterSettingsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = ClusterSettingsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = ClusterSettingsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster_put_settings()
+            .transient("cluster.routing.allocation.enable", "all")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/_cluster/settings", req.url.as_ref());
+        assert_eq!(
+            r#"{"persistent":{},"transient":{"cluster.routing.allocation.enable":"all"}}"#
+                .as_bytes()
+                .to_vec(),
+            req.body
+        );
+    }
+
+    #[test]
+    fn empty_settings_is_err() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cluster_put_settings().inner.into_request();
+
+        assert!(req.is_err());
+    }
+
+    #[test]
+    fn specify_settings() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .cluster_put_settings()
+            .persistent("indices.recovery.max_bytes_per_sec", "50mb")
+            .transient("cluster.routing.allocation.enable", "none")
+            .transient("cluster.routing.rebalance.enable", Value::Null)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected = json!({
+            "persistent": {
+                "indices.recovery.max_bytes_per_sec": "50mb"
+            },
+            "transient": {
+                "cluster.routing.allocation.enable": "none",
+                "cluster.routing.rebalance.enable": null
+            }
+        });
+
+        let actual: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+}
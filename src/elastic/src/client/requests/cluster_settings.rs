@@ -0,0 +1,223 @@
+/*!
+Builders for [cluster settings requests][docs-cluster-settings].
+
+[docs-cluster-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-update-settings.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+
+use client::requests::endpoints::ClusterGetSettingsRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::RequestBuilder;
+use client::responses::ClusterSettingsResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    Error,
+    Result,
+};
+
+/**
+A [cluster settings request][docs-cluster-settings] builder that can be configured before sending.
+
+Call [`Client.cluster_settings`][Client.cluster_settings] to get a `ClusterSettingsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-cluster-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-update-settings.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.cluster_settings]: ../../struct.Client.html#cluster-settings-request
+*/
+pub type ClusterSettingsRequestBuilder<TSender> =
+    RequestBuilder<TSender, ClusterSettingsRequestInner>;
+
+#[doc(hidden)]
+pub struct ClusterSettingsRequestInner;
+
+/**
+# Cluster settings request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ClusterSettingsRequestBuilder`][ClusterSettingsRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request is sent with `flat_settings=true` so settings are keyed by their full name, like `cluster.routing.allocation.enable`.
+    Only settings that have been explicitly set are returned.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether shard allocation has been restricted:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster_settings().send()?;
+
+    if let Some(allocation) = response.setting("cluster.routing.allocation.enable") {
+        println!("allocation: {}", allocation);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ClusterSettingsRequestBuilder]: requests/cluster_settings/type.ClusterSettingsRequestBuilder.html
+    [send-sync]: requests/cluster_settings/type.ClusterSettingsRequestBuilder.html#send-synchronously
+    [send-async]: requests/cluster_settings/type.ClusterSettingsRequestBuilder.html#send-asynchronously
+    */
+    pub fn cluster_settings(&self) -> ClusterSettingsRequestBuilder<TSender> {
+        RequestBuilder::initial(self.clone(), ClusterSettingsRequestInner)
+    }
+}
+
+impl ClusterSettingsRequestInner {
+    fn into_request(self) -> ClusterGetSettingsRequest<'static> {
+        ClusterGetSettingsRequest::new()
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl ClusterSettingsRequestBuilder<SyncSender> {
+    /**
+    Send a `ClusterSettingsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Print the persistent cluster settings:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.cluster_settings().send()?;
+
+    for (key, value) in response.persistent() {
+        println!("{}: {}", key, value);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ClusterSettingsResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .params_fluent(|p| p.url_param("flat_settings", true))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl ClusterSettingsRequestBuilder<AsyncSender> {
+    /**
+    Send a `ClusterSettingsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised cluster settings response.
+
+    # Examples
+
+    Print the persistent cluster settings:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.cluster_settings().send();
+
+    future.and_then(|response| {
+        for (key, value) in response.persistent() {
+            println!("{}: {}", key, value);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .params_fluent(|p| p.url_param("flat_settings", true))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = ClusterSettingsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = ClusterSettingsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = ClusterSettingsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.cluster_settings().inner.into_request();
+
+        assert_eq!("/_cluster/settings", req.url.as_ref());
+    }
+}
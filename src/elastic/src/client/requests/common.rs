@@ -10,6 +10,14 @@ use serde_json::{
     Map,
     Value,
 };
+use std::time::Duration;
+
+use client::requests::script::ScriptDefinition;
+
+pub use client::requests::script::{
+    DefaultParams,
+    ScriptBuilder,
+};
 
 /** Update an indexed document using a new document. */
 #[derive(Serialize)]
@@ -50,13 +58,10 @@ where
     }
 }
 
-/** A default set of script parameters. */
-pub type DefaultParams = Map<String, Value>;
-
 /** Update an indexed document using a script. */
 #[derive(Serialize)]
 pub struct Script<TParams> {
-    script: ScriptInner<TParams>,
+    script: ScriptDefinition<TParams>,
 }
 
 impl Script<DefaultParams> {
@@ -65,102 +70,69 @@ impl Script<DefaultParams> {
     where
         TScript: ToString,
     {
-        ScriptBuilder::new(source).build()
+        Script {
+            script: ScriptDefinition::new(source),
+        }
     }
 }
 
-#[derive(Serialize)]
-struct ScriptInner<TParams> {
-    #[serde(rename = "inline")]
-    source: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    lang: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    params: Option<TParams>,
-}
-
-/** A builder for an update script that can be configured before sending. */
-pub struct ScriptBuilder<TParams> {
-    source: String,
-    lang: Option<String>,
-    params: Option<TParams>,
+impl<TParams> Script<TParams> {
+    pub(crate) fn into_builder(self) -> ScriptBuilder<TParams> {
+        ScriptBuilder::from_script(self.script)
+    }
 }
 
-impl ScriptBuilder<DefaultParams> {
-    /** Create a new script builder using the given source. */
-    pub fn new<TScript>(source: TScript) -> Self
-    where
-        TScript: ToString,
-    {
-        ScriptBuilder {
-            source: source.to_string(),
-            params: None,
-            lang: None,
+impl<TParams> From<ScriptBuilder<TParams>> for Script<TParams> {
+    fn from(builder: ScriptBuilder<TParams>) -> Self {
+        Script {
+            script: builder.build(),
         }
     }
-
-    /** Set a script parameter. */
-    pub fn param<TKey, TValue>(mut self, key: TKey, value: TValue) -> Self
-    where
-        TKey: ToString,
-        TValue: Into<Value>,
-    {
-        let mut params = self.params.unwrap_or_else(DefaultParams::new);
-        params.insert(key.to_string(), value.into());
-
-        self.params = Some(params);
-        self
-    }
 }
 
-impl<TParams> ScriptBuilder<TParams> {
-    pub(crate) fn from_script(script: Script<TParams>) -> Self {
-        let script = script.script;
+/**
+Format a duration as an Elasticsearch [time unit][docs-time-units].
 
-        ScriptBuilder {
-            source: script.source,
-            lang: script.lang,
-            params: script.params,
-        }
-    }
+The duration is formatted using the largest unit that can represent it exactly, like `5s` or `1500ms`.
 
-    /** Set the language for the update script. */
-    pub fn lang<TLang>(mut self, lang: Option<TLang>) -> Self
-    where
-        TLang: ToString,
-    {
-        self.lang = lang.map(|lang| lang.to_string());
-        self
+[docs-time-units]: https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#time-units
+*/
+pub(crate) fn time_unit(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos() as u64;
+
+    if nanos == 0 {
+        format!("{}s", secs)
+    } else if nanos % 1_000_000 == 0 {
+        format!("{}ms", secs * 1_000 + nanos / 1_000_000)
+    } else if nanos % 1_000 == 0 {
+        format!("{}micros", secs * 1_000_000 + nanos / 1_000)
+    } else {
+        format!("{}nanos", secs * 1_000_000_000 + nanos)
     }
+}
 
-    /** Specify a new set of parameters for the update script. */
-    pub fn params<TNewParams>(self, params: TNewParams) -> ScriptBuilder<TNewParams> {
-        ScriptBuilder {
-            source: self.source,
-            lang: self.lang,
-            params: Some(params),
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_unit_secs() {
+        assert_eq!("5s", time_unit(Duration::from_secs(5)));
     }
 
-    pub(crate) fn build(self) -> Script<TParams> {
-        Script {
-            script: ScriptInner {
-                source: self.source,
-                params: self.params,
-                lang: self.lang,
-            },
-        }
+    #[test]
+    fn time_unit_millis() {
+        assert_eq!("1500ms", time_unit(Duration::from_millis(1500)));
     }
-}
 
-impl From<String> for ScriptBuilder<DefaultParams> {
-    fn from(source: String) -> Self {
-        ScriptBuilder::new(source)
+    #[test]
+    fn time_unit_micros() {
+        assert_eq!("1500micros", time_unit(Duration::new(0, 1_500_000)));
     }
-}
 
-impl<'a> From<&'a str> for ScriptBuilder<DefaultParams> {
-    fn from(source: &'a str) -> Self {
-        ScriptBuilder::new(source)
+    #[test]
+    fn time_unit_nanos() {
+        assert_eq!("1000000001nanos", time_unit(Duration::new(1, 1)));
     }
 }
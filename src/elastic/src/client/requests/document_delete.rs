@@ -9,6 +9,7 @@ use futures::{
     Poll,
 };
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use client::requests::endpoints::DeleteRequest;
 use client::requests::params::{
@@ -16,6 +17,7 @@ use client::requests::params::{
     Index,
     Type,
 };
+use client::requests::common::time_unit;
 use client::requests::raw::RawRequestInner;
 use client::requests::RequestBuilder;
 use client::responses::DeleteResponse;
@@ -215,6 +217,19 @@ where
         self.inner.ty = ty.into();
         self
     }
+
+    /**
+    Set a server-side timeout for the delete request.
+
+    This is how long Elasticsearch will wait for the primary shard to become available before failing the request.
+
+    This is separate from any timeout configured on the underlying http client, which will abort the request on the client side.
+    */
+    pub fn server_timeout(self, timeout: Duration) -> Self {
+        let timeout = time_unit(timeout);
+
+        self.params_fluent(move |p| p.url_param("timeout", &timeout))
+    }
 }
 
 /**
@@ -354,6 +369,7 @@ impl Future for Pending {
 #[cfg(test)]
 mod tests {
     use prelude::*;
+    use std::time::Duration;
 
     #[derive(ElasticType)]
     struct TestDoc {}
@@ -398,4 +414,18 @@ mod tests {
 
         assert_eq!("/testdoc/new-ty/1", req.url.as_ref());
     }
+
+    #[test]
+    fn specify_server_timeout() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .delete("1")
+            .server_timeout(Duration::from_millis(1500))
+            .params_builder
+            .into_value(|| RequestParams::default());
+
+        assert_eq!((15, Some(String::from("?timeout=1500ms"))), params.get_url_qry());
+    }
 }
@@ -5,6 +5,7 @@ Builders for [index requests][docs-index].
 */
 
 use std::borrow::Cow;
+use std::time::Duration;
 
 use futures::{
     Future,
@@ -19,6 +20,7 @@ use client::requests::params::{
     Index,
     Type,
 };
+use client::requests::common::time_unit;
 use client::requests::raw::RawRequestInner;
 use client::requests::RequestBuilder;
 use client::responses::IndexResponse;
@@ -248,6 +250,19 @@ where
         self.inner.id = Some(id.into());
         self
     }
+
+    /**
+    Set a server-side timeout for the index request.
+
+    This is how long Elasticsearch will wait for the primary shard to become available before failing the request.
+
+    This is separate from any timeout configured on the underlying http client, which will abort the request on the client side.
+    */
+    pub fn server_timeout(self, timeout: Duration) -> Self {
+        let timeout = time_unit(timeout);
+
+        self.params_fluent(move |p| p.url_param("timeout", &timeout))
+    }
 }
 
 /**
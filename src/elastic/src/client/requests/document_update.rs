@@ -11,6 +11,7 @@ use futures::{
 use serde::ser::Serialize;
 use serde_json;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use client::requests::endpoints::UpdateRequest;
 use client::requests::params::{
@@ -18,6 +19,7 @@ use client::requests::params::{
     Index,
     Type,
 };
+use client::requests::common::time_unit;
 use client::requests::raw::RawRequestInner;
 use client::requests::RequestBuilder;
 use client::responses::UpdateResponse;
@@ -330,6 +332,19 @@ where
         self
     }
 
+    /**
+    Set a server-side timeout for the update request.
+
+    This is how long Elasticsearch will wait for the primary shard to become available before failing the request.
+
+    This is separate from any timeout configured on the underlying http client, which will abort the request on the client side.
+    */
+    pub fn server_timeout(self, timeout: Duration) -> Self {
+        let timeout = time_unit(timeout);
+
+        self.params_fluent(move |p| p.url_param("timeout", &timeout))
+    }
+
     /**
     Update the source using a document.
 
@@ -415,7 +430,7 @@ where
     }
 
     /**
-    Update the source using [an inline script][painless-lang].
+    Update the source using [an inline or stored script][painless-lang].
 
     # Examples
 
@@ -479,6 +494,39 @@ where
     # }
     ```
 
+    Update the `title` property of a document using a stored script:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use]
+    # extern crate serde_derive;
+    # #[macro_use]
+    # extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::client::requests::document_update::ScriptBuilder;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType {
+    #     pub id: String,
+    #     pub title: String,
+    #     pub timestamp: Date<DefaultDateMapping>
+    # }
+    # let client = SyncClientBuilder::new().build()?;
+    let script = ScriptBuilder::stored("update-title")
+        .param("newTitle", "New Title");
+
+    let response = client.document::<MyType>()
+                         .update(1)
+                         .script(script)
+                         .send()?;
+
+    assert!(response.updated());
+    # Ok(())
+    # }
+    ```
+
     [painless-lang]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-painless.html
      */
     pub fn script<TScript, TParams>(
@@ -488,11 +536,13 @@ where
     where
         TScript: Into<ScriptBuilder<TParams>>,
     {
+        let builder: ScriptBuilder<TParams> = builder.into();
+
         RequestBuilder::new(
             self.client,
             self.params_builder,
             UpdateRequestInner {
-                body: builder.into().build(),
+                body: Script::from(builder),
                 index: self.inner.index,
                 ty: self.inner.ty,
                 id: self.inner.id,
@@ -879,6 +929,32 @@ mod tests {
         assert_eq!(expected_body.to_string(), actual_body.to_string());
     }
 
+    #[test]
+    fn specify_stored_script() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .update("1")
+            .script(ScriptBuilder::stored("my-script").param("str", "some value"))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "script": {
+                "id": "my-script",
+                "params": {
+                    "str": "some value"
+                }
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
     #[test]
     fn specify_script_fluent() {
         let client = SyncClientBuilder::new().build().unwrap();
@@ -32,7 +32,11 @@ pub use self::raw::RawRequestBuilder;
 
 // Search requests
 pub mod search;
+pub mod search_template;
+pub mod validate_query;
 pub use self::search::SearchRequestBuilder;
+pub use self::search_template::SearchTemplateRequestBuilder;
+pub use self::validate_query::ValidateQueryRequestBuilder;
 
 // Document requests
 pub mod document_delete;
@@ -64,7 +68,28 @@ pub mod ping;
 pub use self::bulk::BulkRequestBuilder;
 pub use self::ping::PingRequestBuilder;
 
+// Diagnostic requests
+pub mod cat_nodes;
+pub mod cat_shards;
+pub mod nodes_hot_threads;
+pub mod tasks_list;
+pub use self::cat_nodes::CatNodesRequestBuilder;
+pub use self::cat_shards::CatShardsRequestBuilder;
+pub use self::nodes_hot_threads::NodesHotThreadsRequestBuilder;
+pub use self::tasks_list::TasksListRequestBuilder;
+
+// Cluster requests
+pub mod cluster_put_settings;
+pub mod cluster_settings;
+pub use self::cluster_put_settings::ClusterPutSettingsRequestBuilder;
+pub use self::cluster_settings::ClusterSettingsRequestBuilder;
+
+// Snapshot requests
+pub mod snapshot_status;
+pub use self::snapshot_status::SnapshotStatusRequestBuilder;
+
 pub mod common;
+pub mod script;
 
 /**
 A builder for a request.
@@ -259,6 +284,10 @@ pub mod prelude {
 
     pub use super::{
         empty_body,
+        CatNodesRequestBuilder,
+        CatShardsRequestBuilder,
+        ClusterPutSettingsRequestBuilder,
+        ClusterSettingsRequestBuilder,
         DefaultBody,
         DeleteRequestBuilder,
         GetRequestBuilder,
@@ -267,10 +296,15 @@ pub mod prelude {
         IndexDeleteRequestBuilder,
         IndexOpenRequestBuilder,
         IndexRequestBuilder,
+        NodesHotThreadsRequestBuilder,
         PingRequestBuilder,
         PutMappingRequestBuilder,
         RawRequestBuilder,
         SearchRequestBuilder,
+        SearchTemplateRequestBuilder,
+        SnapshotStatusRequestBuilder,
+        TasksListRequestBuilder,
         UpdateRequestBuilder,
+        ValidateQueryRequestBuilder,
     };
 }
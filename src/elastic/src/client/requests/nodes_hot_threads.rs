@@ -0,0 +1,324 @@
+/*!
+Builders for [nodes hot threads requests][docs-hot-threads].
+
+[docs-hot-threads]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-nodes-hot-threads.html
+*/
+
+use std::io::Read;
+
+use futures::{
+    Future,
+    Poll,
+    Stream,
+};
+use serde_json::Value;
+
+use client::requests::endpoints::NodesHotThreadsRequest;
+use client::requests::params::NodeId;
+use client::requests::raw::RawRequestInner;
+use client::requests::RequestBuilder;
+use client::responses::parse::parse;
+use client::responses::NodesHotThreadsResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    self,
+    Error,
+    Result,
+};
+use http::StatusCode;
+
+/**
+A [nodes hot threads request][docs-hot-threads] builder that can be configured before sending.
+
+Call [`Client.nodes_hot_threads`][Client.nodes_hot_threads] to get a `NodesHotThreadsRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-hot-threads]: https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-nodes-hot-threads.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.nodes_hot_threads]: ../../struct.Client.html#nodes-hot-threads-request
+*/
+pub type NodesHotThreadsRequestBuilder<TSender> =
+    RequestBuilder<TSender, NodesHotThreadsRequestInner>;
+
+#[doc(hidden)]
+pub struct NodesHotThreadsRequestInner {
+    node_id: Option<NodeId<'static>>,
+}
+
+/**
+# Nodes hot threads request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`NodesHotThreadsRequestBuilder`][NodesHotThreadsRequestBuilder] with this `Client` that can be configured before sending.
+
+    Hot threads are returned as plain text rather than JSON.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Get the hot threads for all nodes in the cluster:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.nodes_hot_threads().send()?;
+
+    println!("{}", response.text());
+    # Ok(())
+    # }
+    ```
+
+    [NodesHotThreadsRequestBuilder]: requests/nodes_hot_threads/type.NodesHotThreadsRequestBuilder.html
+    [builder-methods]: requests/nodes_hot_threads/type.NodesHotThreadsRequestBuilder.html#builder-methods
+    [send-sync]: requests/nodes_hot_threads/type.NodesHotThreadsRequestBuilder.html#send-synchronously
+    [send-async]: requests/nodes_hot_threads/type.NodesHotThreadsRequestBuilder.html#send-asynchronously
+    */
+    pub fn nodes_hot_threads(&self) -> NodesHotThreadsRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.clone(),
+            NodesHotThreadsRequestInner { node_id: None },
+        )
+    }
+}
+
+impl NodesHotThreadsRequestInner {
+    fn into_request(self) -> NodesHotThreadsRequest<'static> {
+        match self.node_id {
+            Some(node_id) => NodesHotThreadsRequest::for_node_id(node_id),
+            None => NodesHotThreadsRequest::new(),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `NodesHotThreadsRequestBuilder` before sending it.
+*/
+impl<TSender> NodesHotThreadsRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set the nodes to get hot threads for.
+
+    If no node is specified then all nodes will be used.
+    */
+    pub fn node_id(mut self, node_id: impl Into<NodeId<'static>>) -> Self {
+        self.inner.node_id = Some(node_id.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl NodesHotThreadsRequestBuilder<SyncSender> {
+    /**
+    Send a `NodesHotThreadsRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and its body has been read.
+
+    # Examples
+
+    Get the hot threads for a single node:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.nodes_hot_threads()
+                         .node_id("node-1")
+                         .send()?;
+
+    println!("{}", response.text());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<NodesHotThreadsResponse> {
+        let req = self.inner.into_request();
+
+        let res = RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?;
+
+        let status = res.status();
+        let mut body = Vec::new();
+        res.into_raw()
+            .read_to_end(&mut body)
+            .map_err(|e| error::response(status, e))?;
+
+        into_response(status, body)
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl NodesHotThreadsRequestBuilder<AsyncSender> {
+    /**
+    Send a `NodesHotThreadsRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the hot threads response once its body has been read.
+
+    # Examples
+
+    Get the hot threads for all nodes in the cluster:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.nodes_hot_threads().send();
+
+    future.and_then(|response| {
+        println!("{}", response.text());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| {
+                    let status = res.status();
+
+                    res.into_raw()
+                        .concat2()
+                        .and_then(move |body| into_response(status, body))
+                });
+
+        Pending::new(res_future)
+    }
+}
+
+/**
+Convert a raw hot threads body into a response.
+
+Successful responses are plain text so they're never deserialised.
+Errors are still returned as JSON so they're parsed the same way as any other API error.
+*/
+fn into_response<B>(status: StatusCode, body: B) -> Result<NodesHotThreadsResponse>
+where
+    B: AsRef<[u8]>,
+{
+    if !status.is_success() {
+        parse::<Value>()
+            .from_slice(status, body.as_ref())
+            .map_err(|e| error::response(status, e))?;
+    }
+
+    let text = String::from_utf8_lossy(body.as_ref()).into_owned();
+
+    Ok(NodesHotThreadsResponse::from(text))
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = NodesHotThreadsResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = NodesHotThreadsResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = NodesHotThreadsResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::into_response;
+    use http::StatusCode;
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.nodes_hot_threads().inner.into_request();
+
+        assert_eq!("/_nodes/hot_threads", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_node_id() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .nodes_hot_threads()
+            .node_id("node-1")
+            .inner
+            .into_request();
+
+        assert_eq!("/_nodes/node-1/hot_threads", req.url.as_ref());
+    }
+
+    #[test]
+    fn plain_text_body_is_not_parsed() {
+        let body = "::: {node-1}{abc}{127.0.0.1}{127.0.0.1:9300}\n   Hot threads at 2018-01-01T00:00:00Z";
+
+        let res = into_response(StatusCode::OK, body).unwrap();
+
+        assert_eq!(body, res.text());
+    }
+
+    #[test]
+    fn error_body_is_parsed() {
+        let body = r#"{ "error": { "type": "illegal_argument_exception", "reason": "bad" }, "status": 400 }"#;
+
+        let res = into_response(StatusCode::BAD_REQUEST, body);
+
+        match res {
+            Err(Error::Api(_)) => (),
+            _ => panic!("expected an API error"),
+        }
+    }
+}
@@ -0,0 +1,271 @@
+/*!
+Types for [scripts][docs-scripting] that can be used in requests.
+
+A [`ScriptDefinition`][ScriptDefinition] is either an inline script with its source, or a stored script referenced by id.
+The same type is used wherever a request accepts a script, like the [`Script`][Script] used for updating a document.
+
+[docs-scripting]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html
+[ScriptDefinition]: struct.ScriptDefinition.html
+[Script]: ../common/struct.Script.html
+*/
+
+use serde::ser::{
+    Serialize,
+    SerializeMap,
+    Serializer,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+
+/** A default set of script parameters. */
+pub type DefaultParams = Map<String, Value>;
+
+/**
+A script that can be sent to Elasticsearch.
+
+Use a [`ScriptBuilder`][ScriptBuilder] to create a `ScriptDefinition`.
+
+[ScriptBuilder]: struct.ScriptBuilder.html
+*/
+pub struct ScriptDefinition<TParams> {
+    source: ScriptSource,
+    lang: Option<String>,
+    params: Option<TParams>,
+}
+
+enum ScriptSource {
+    Inline(String),
+    Stored(String),
+}
+
+impl ScriptDefinition<DefaultParams> {
+    /** Create a new inline script using the given source. */
+    pub(crate) fn new<TScript>(source: TScript) -> Self
+    where
+        TScript: ToString,
+    {
+        ScriptBuilder::new(source).build()
+    }
+}
+
+impl<TParams> Serialize for ScriptDefinition<TParams>
+where
+    TParams: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+
+        match self.source {
+            ScriptSource::Inline(ref source) => {
+                map.serialize_entry("inline", source)?;
+
+                if let Some(ref lang) = self.lang {
+                    map.serialize_entry("lang", lang)?;
+                }
+            }
+            ScriptSource::Stored(ref id) => map.serialize_entry("id", id)?,
+        }
+
+        if let Some(ref params) = self.params {
+            map.serialize_entry("params", params)?;
+        }
+
+        map.end()
+    }
+}
+
+/**
+A builder for a script.
+
+Scripts are either `inline`, where the source is sent with the request, or `stored`, where the source has already been stored in the cluster and is referenced by its id.
+*/
+pub struct ScriptBuilder<TParams> {
+    source: ScriptSource,
+    lang: Option<String>,
+    params: Option<TParams>,
+}
+
+impl ScriptBuilder<DefaultParams> {
+    /** Create a new inline script builder using the given source. */
+    pub fn new<TScript>(source: TScript) -> Self
+    where
+        TScript: ToString,
+    {
+        ScriptBuilder::inline(source)
+    }
+
+    /** Create a new inline script builder using the given source. */
+    pub fn inline<TScript>(source: TScript) -> Self
+    where
+        TScript: ToString,
+    {
+        ScriptBuilder {
+            source: ScriptSource::Inline(source.to_string()),
+            params: None,
+            lang: None,
+        }
+    }
+
+    /** Create a new script builder for a stored script with the given id. */
+    pub fn stored<TId>(id: TId) -> Self
+    where
+        TId: ToString,
+    {
+        ScriptBuilder {
+            source: ScriptSource::Stored(id.to_string()),
+            params: None,
+            lang: None,
+        }
+    }
+
+    /** Set a script parameter. */
+    pub fn param<TKey, TValue>(mut self, key: TKey, value: TValue) -> Self
+    where
+        TKey: ToString,
+        TValue: Into<Value>,
+    {
+        let mut params = self.params.unwrap_or_else(DefaultParams::new);
+        params.insert(key.to_string(), value.into());
+
+        self.params = Some(params);
+        self
+    }
+}
+
+impl<TParams> ScriptBuilder<TParams> {
+    pub(crate) fn from_script(script: ScriptDefinition<TParams>) -> Self {
+        ScriptBuilder {
+            source: script.source,
+            lang: script.lang,
+            params: script.params,
+        }
+    }
+
+    /**
+    Set the language for the script.
+
+    If no language is set then Elasticsearch will use `painless`.
+    The language is ignored for stored scripts, because it's set when the script is stored.
+    */
+    pub fn lang<TLang>(mut self, lang: Option<TLang>) -> Self
+    where
+        TLang: ToString,
+    {
+        self.lang = lang.map(|lang| lang.to_string());
+        self
+    }
+
+    /** Specify a new set of parameters for the script. */
+    pub fn params<TNewParams>(self, params: TNewParams) -> ScriptBuilder<TNewParams> {
+        ScriptBuilder {
+            source: self.source,
+            lang: self.lang,
+            params: Some(params),
+        }
+    }
+
+    /** Build the script. */
+    pub fn build(self) -> ScriptDefinition<TParams> {
+        ScriptDefinition {
+            source: self.source,
+            params: self.params,
+            lang: self.lang,
+        }
+    }
+}
+
+impl From<String> for ScriptBuilder<DefaultParams> {
+    fn from(source: String) -> Self {
+        ScriptBuilder::inline(source)
+    }
+}
+
+impl<'a> From<&'a str> for ScriptBuilder<DefaultParams> {
+    fn from(source: &'a str) -> Self {
+        ScriptBuilder::inline(source)
+    }
+}
+
+impl<TParams> From<ScriptDefinition<TParams>> for ScriptBuilder<TParams> {
+    fn from(script: ScriptDefinition<TParams>) -> Self {
+        ScriptBuilder::from_script(script)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn serialize_inline_script() {
+        let script = ScriptBuilder::inline("ctx._source.a = params.str")
+            .lang(Some("painless"))
+            .param("str", "some value")
+            .build();
+
+        let expected = json!({
+            "inline": "ctx._source.a = params.str",
+            "lang": "painless",
+            "params": {
+                "str": "some value"
+            }
+        });
+
+        let actual = serde_json::to_value(&script).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn serialize_stored_script() {
+        let script = ScriptBuilder::stored("my-script")
+            .lang(Some("painless"))
+            .param("str", "some value")
+            .build();
+
+        let expected = json!({
+            "id": "my-script",
+            "params": {
+                "str": "some value"
+            }
+        });
+
+        let actual = serde_json::to_value(&script).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn serialize_typed_params() {
+        #[derive(Serialize)]
+        struct MyParams {
+            a: &'static str,
+            b: i32,
+        }
+
+        let script = ScriptBuilder::stored("my-script")
+            .params(MyParams {
+                a: "some value",
+                b: 42,
+            })
+            .build();
+
+        let expected = json!({
+            "id": "my-script",
+            "params": {
+                "a": "some value",
+                "b": 42
+            }
+        });
+
+        let actual = serde_json::to_value(&script).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+}
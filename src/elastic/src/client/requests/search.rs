@@ -10,12 +10,14 @@ use futures::{
 };
 use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use client::requests::endpoints::SearchRequest;
 use client::requests::params::{
     Index,
     Type,
 };
+use client::requests::common::time_unit;
 use client::requests::raw::RawRequestInner;
 use client::requests::{
     empty_body,
@@ -281,6 +283,22 @@ where
         self
     }
 
+    /**
+    Set a server-side timeout for the search request.
+
+    If the timeout expires then Elasticsearch will return the hits collected so far and the response's [`timed_out`][SearchResponse.timed_out] flag will be `true`.
+    The results may be partial in that case.
+
+    This is separate from any timeout configured on the underlying http client, which will abort the request on the client side.
+
+    [SearchResponse.timed_out]: ../../responses/struct.SearchResponse.html#method.timed_out
+    */
+    pub fn server_timeout(self, timeout: Duration) -> Self {
+        let timeout = time_unit(timeout);
+
+        self.params_fluent(move |p| p.url_param("timeout", &timeout))
+    }
+
     /**
     Set the body for the search request.
 
@@ -454,6 +472,7 @@ where
 mod tests {
     use prelude::*;
     use serde_json::Value;
+    use std::time::Duration;
 
     #[test]
     fn default_request() {
@@ -494,4 +513,17 @@ mod tests {
 
         assert_eq!("{}", req.body);
     }
+
+    #[test]
+    fn specify_server_timeout() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .search::<Value>()
+            .server_timeout(Duration::from_secs(5))
+            .params_builder
+            .into_value(|| RequestParams::default());
+
+        assert_eq!((11, Some(String::from("?timeout=5s"))), params.get_url_qry());
+    }
 }
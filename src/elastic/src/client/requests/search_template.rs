@@ -0,0 +1,571 @@
+/*!
+Builders for [search template requests][docs-search-template].
+
+[docs-search-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{
+    self,
+    Value,
+};
+use std::marker::PhantomData;
+
+use client::requests::endpoints::SearchTemplateRequest;
+use client::requests::params::{
+    Index,
+    Type,
+};
+use client::requests::raw::RawRequestInner;
+use client::requests::RequestBuilder;
+use client::responses::SearchResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    self,
+    Error,
+    Result,
+};
+
+pub use client::requests::common::DefaultParams;
+
+/**
+A [search template request][docs-search-template] builder that can be configured before sending.
+
+Call [`Client.search_template`][Client.search_template] to get a `SearchTemplateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-search-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.search_template]: ../../struct.Client.html#search-template-request
+*/
+pub type SearchTemplateRequestBuilder<TSender, TDocument> =
+    RequestBuilder<TSender, SearchTemplateRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct SearchTemplateRequestInner<TDocument> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    template: Option<Template>,
+    params: DefaultParams,
+    _marker: PhantomData<TDocument>,
+}
+
+enum Template {
+    Id(String),
+    Source(Value),
+}
+
+#[derive(Serialize)]
+struct SearchTemplateBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<&'a Value>,
+    params: &'a DefaultParams,
+}
+
+/**
+# Search template request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SearchTemplateRequestBuilder`][SearchTemplateRequestBuilder] with this `Client` that can be configured before sending.
+
+    Parameters are substituted into the template by Elasticsearch, so the response is a regular [`SearchResponse`][SearchResponse].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Run a stored search template called `my-template` for a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template::<MyType>()
+                         .index("myindex")
+                         .id("my-template")
+                         .param("query_string", "a query string")
+                         .send()?;
+
+    // Iterate through the hits (of type `MyType`)
+    for hit in response.hits() {
+        println!("{:?}", hit);
+    }
+    # Ok(())
+    # }
+    ```
+
+    Templates can be stored ahead of time by sending a raw [`PutScriptRequest`][PutScriptRequest]:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let body = json!({
+        "script": {
+            "lang": "mustache",
+            "source": {
+                "query": {
+                    "match": {
+                        "title": "{{query_string}}"
+                    }
+                }
+            }
+        }
+    });
+
+    client.request(PutScriptRequest::for_id("my-template", body.to_string()))
+          .send()?
+          .into_response::<CommandResponse>()?;
+    # Ok(())
+    # }
+    ```
+
+    [SearchTemplateRequestBuilder]: requests/search_template/type.SearchTemplateRequestBuilder.html
+    [builder-methods]: requests/search_template/type.SearchTemplateRequestBuilder.html#builder-methods
+    [send-sync]: requests/search_template/type.SearchTemplateRequestBuilder.html#send-synchronously
+    [send-async]: requests/search_template/type.SearchTemplateRequestBuilder.html#send-asynchronously
+    [SearchResponse]: responses/struct.SearchResponse.html
+    [PutScriptRequest]: requests/endpoints/struct.PutScriptRequest.html
+    [documents-mod]: ../../types/document/index.html
+    */
+    pub fn search_template<TDocument>(&self) -> SearchTemplateRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DeserializeOwned,
+    {
+        RequestBuilder::initial(self.clone(), SearchTemplateRequestInner::new())
+    }
+}
+
+impl<TDocument> SearchTemplateRequestInner<TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    fn new() -> Self {
+        SearchTemplateRequestInner {
+            index: None,
+            ty: None,
+            template: None,
+            params: DefaultParams::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    fn into_request(self) -> Result<SearchTemplateRequest<'static, Vec<u8>>> {
+        let body = {
+            let (id, source) = match self.template {
+                Some(Template::Id(ref id)) => (Some(id.as_ref()), None),
+                Some(Template::Source(ref source)) => (None, Some(source)),
+                None => {
+                    return Err(error::request(error::message(
+                        "a search template needs either an `id` or a `source`",
+                    )))
+                }
+            };
+
+            serde_json::to_vec(&SearchTemplateBody {
+                id: id,
+                source: source,
+                params: &self.params,
+            })
+            .map_err(error::request)?
+        };
+
+        let index = self.index.unwrap_or_else(|| "_all".into());
+
+        Ok(match self.ty {
+            Some(ty) => SearchTemplateRequest::for_index_ty(index, ty, body),
+            None => SearchTemplateRequest::for_index(index, body),
+        })
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `SearchTemplateRequestBuilder` before sending it.
+*/
+impl<TSender, TDocument> SearchTemplateRequestBuilder<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices for the search template request.
+
+    If no index is specified then `_all` will be used.
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /** Set the types for the search template request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = Some(ty.into());
+        self
+    }
+
+    /**
+    Use a stored template with the given id.
+
+    Either `id` or `source` must be set before the request is sent.
+
+    This replaces any inline template set with `source`.
+    */
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.inner.template = Some(Template::Id(id.into()));
+        self
+    }
+
+    /**
+    Use an inline template.
+
+    The source can be a mustache string or a JSON object.
+    This replaces any stored template set with `id`.
+    */
+    pub fn source(mut self, source: impl Into<Value>) -> Self {
+        self.inner.template = Some(Template::Source(source.into()));
+        self
+    }
+
+    /** Set a template parameter. */
+    pub fn param(mut self, key: impl ToString, value: impl Into<Value>) -> Self {
+        self.inner.params.insert(key.to_string(), value.into());
+        self
+    }
+
+    /**
+    Specify a new set of template parameters.
+
+    This replaces any parameters set with `param`.
+    */
+    pub fn template_params(mut self, params: DefaultParams) -> Self {
+        self.inner.params = params;
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> SearchTemplateRequestBuilder<SyncSender, TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    /**
+    Send a `SearchTemplateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Run an inline search template for a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.search_template::<MyType>()
+                         .index("myindex")
+                         .source(json!({
+                             "query": {
+                                 "match": {
+                                     "title": "{{query_string}}"
+                                 }
+                             }
+                         }))
+                         .param("query_string", "a query string")
+                         .send()?;
+
+    // Iterate through the hits (of type `MyType`)
+    for hit in response.hits() {
+        println!("{:?}", hit);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    [documents-mod]: ../../../types/document/index.html
+    */
+    pub fn send(self) -> Result<SearchResponse<TDocument>> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TDocument> SearchTemplateRequestBuilder<AsyncSender, TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    /**
+    Send a `SearchTemplateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised search response.
+
+    # Examples
+
+    Run a stored search template for a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # extern crate serde;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.search_template::<MyType>()
+                       .index("myindex")
+                       .id("my-template")
+                       .param("query_string", "a query string")
+                       .send();
+
+    future.and_then(|response| {
+        // Iterate through the hits (of type `MyType`)
+        for hit in response.hits() {
+            println!("{:?}", hit);
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    [documents-mod]: ../../../types/document/index.html
+    */
+    pub fn send(self) -> Pending<TDocument> {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let req_future = client.sender.maybe_async(move || inner.into_request());
+
+        let res_future = req_future.and_then(move |req| {
+            RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response())
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending<TDocument> {
+    inner: Box<Future<Item = SearchResponse<TDocument>, Error = Error>>,
+}
+
+impl<TDocument> Pending<TDocument> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = SearchResponse<TDocument>, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<TDocument> Future for Pending<TDocument>
+where
+    TDocument: DeserializeOwned + Send + 'static,
+{
+    type Item = SearchResponse<TDocument>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template::<Value>()
+            .id("my-template")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/_all/_search/template", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template::<Value>()
+            .index("new-idx")
+            .id("my-template")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/new-idx/_search/template", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template::<Value>()
+            .ty("new-ty")
+            .id("my-template")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/_all/new-ty/_search/template", req.url.as_ref());
+    }
+
+    #[test]
+    fn missing_template_is_err() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.search_template::<Value>().inner.into_request();
+
+        assert!(req.is_err());
+    }
+
+    #[test]
+    fn specify_stored_template() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template::<Value>()
+            .id("my-template")
+            .param("a", "string")
+            .param("b", 123)
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "id": "my-template",
+            "params": {
+                "a": "string",
+                "b": 123
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
+    #[test]
+    fn specify_inline_template() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let source = json!({
+            "query": {
+                "match": {
+                    "title": "{{query_string}}"
+                }
+            }
+        });
+
+        let req = client
+            .search_template::<Value>()
+            .source(source.clone())
+            .param("query_string", "a query string")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "source": source,
+            "params": {
+                "query_string": "a query string"
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+
+    #[test]
+    fn source_replaces_id() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .search_template::<Value>()
+            .id("my-template")
+            .source("{\"query\":{\"match_all\":{}}}")
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "source": "{\"query\":{\"match_all\":{}}}",
+            "params": {}
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(expected_body.to_string(), actual_body.to_string());
+    }
+}
@@ -0,0 +1,242 @@
+/*!
+Builders for [snapshot status requests][docs-snapshot-status].
+
+[docs-snapshot-status]: https://www.elastic.co/guide/en/elasticsearch/reference/master/modules-snapshots.html#_snapshot_status
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+
+use client::requests::endpoints::SnapshotStatusRequest;
+use client::requests::params::{
+    Repository,
+    Snapshot,
+};
+use client::requests::raw::RawRequestInner;
+use client::requests::RequestBuilder;
+use client::responses::SnapshotStatusResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    Error,
+    Result,
+};
+
+/**
+A [snapshot status request][docs-snapshot-status] builder that can be configured before sending.
+
+Call [`Client.snapshot_status`][Client.snapshot_status] to get a `SnapshotStatusRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-snapshot-status]: https://www.elastic.co/guide/en/elasticsearch/reference/master/modules-snapshots.html#_snapshot_status
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.snapshot_status]: ../../struct.Client.html#snapshot-status-request
+*/
+pub type SnapshotStatusRequestBuilder<TSender> =
+    RequestBuilder<TSender, SnapshotStatusRequestInner>;
+
+#[doc(hidden)]
+pub struct SnapshotStatusRequestInner {
+    repository: Repository<'static>,
+    snapshot: Snapshot<'static>,
+}
+
+/**
+# Snapshot status request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`SnapshotStatusRequestBuilder`][SnapshotStatusRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether a snapshot called `snapshot_1` in the `my_backup` repository finished successfully:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_status("my_backup", "snapshot_1").send()?;
+
+    for snapshot in response.iter() {
+        match *snapshot.state() {
+            SnapshotState::Success => println!("{} succeeded with {} failed shards", snapshot.snapshot(), snapshot.shards_stats().failed()),
+            SnapshotState::Failed => println!("{} failed", snapshot.snapshot()),
+            ref state => println!("{} is {:?}", snapshot.snapshot(), state),
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SnapshotStatusRequestBuilder]: requests/snapshot_status/type.SnapshotStatusRequestBuilder.html
+    [send-sync]: requests/snapshot_status/type.SnapshotStatusRequestBuilder.html#send-synchronously
+    [send-async]: requests/snapshot_status/type.SnapshotStatusRequestBuilder.html#send-asynchronously
+    */
+    pub fn snapshot_status(
+        &self,
+        repository: impl Into<Repository<'static>>,
+        snapshot: impl Into<Snapshot<'static>>,
+    ) -> SnapshotStatusRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.clone(),
+            SnapshotStatusRequestInner {
+                repository: repository.into(),
+                snapshot: snapshot.into(),
+            },
+        )
+    }
+}
+
+impl SnapshotStatusRequestInner {
+    fn into_request(self) -> SnapshotStatusRequest<'static> {
+        SnapshotStatusRequest::for_repository_snapshot(self.repository, self.snapshot)
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl SnapshotStatusRequestBuilder<SyncSender> {
+    /**
+    Send a `SnapshotStatusRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Get the status of a snapshot called `snapshot_1` in the `my_backup` repository:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.snapshot_status("my_backup", "snapshot_1").send()?;
+
+    for snapshot in response.iter() {
+        println!("{}: {:?}", snapshot.snapshot(), snapshot.state());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<SnapshotStatusResponse> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl SnapshotStatusRequestBuilder<AsyncSender> {
+    /**
+    Send a `SnapshotStatusRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised snapshot status response.
+
+    # Examples
+
+    Get the status of a snapshot called `snapshot_1` in the `my_backup` repository:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.snapshot_status("my_backup", "snapshot_1").send();
+
+    future.and_then(|response| {
+        for snapshot in response.iter() {
+            println!("{}: {:?}", snapshot.snapshot(), snapshot.state());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = SnapshotStatusResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = SnapshotStatusResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = SnapshotStatusResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .snapshot_status("my_backup", "snapshot_1")
+            .inner
+            .into_request();
+
+        assert_eq!("/_snapshot/my_backup/snapshot_1/_status", req.url.as_ref());
+    }
+}
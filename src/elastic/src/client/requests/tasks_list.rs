@@ -0,0 +1,256 @@
+/*!
+Builders for [task listing requests][docs-tasks].
+
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/master/tasks.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+
+use client::requests::endpoints::TasksListRequest;
+use client::requests::raw::RawRequestInner;
+use client::requests::RequestBuilder;
+use client::responses::TasksListResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    Error,
+    Result,
+};
+
+/**
+A [task listing request][docs-tasks] builder that can be configured before sending.
+
+Call [`Client.tasks_list`][Client.tasks_list] to get a `TasksListRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-tasks]: https://www.elastic.co/guide/en/elasticsearch/reference/master/tasks.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.tasks_list]: ../../struct.Client.html#task-listing-request
+*/
+pub type TasksListRequestBuilder<TSender> = RequestBuilder<TSender, TasksListRequestInner>;
+
+#[doc(hidden)]
+pub struct TasksListRequestInner {
+    detailed: bool,
+}
+
+/**
+# Task listing request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`TasksListRequestBuilder`][TasksListRequestBuilder] with this `Client` that can be configured before sending.
+
+    Tasks are listed with their details by default.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    List the tasks running in the cluster:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.tasks_list().send()?;
+
+    for task in response.tasks() {
+        println!("{}: {} ({:?})", task.task_id(), task.action(), task.running_time());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [TasksListRequestBuilder]: requests/tasks_list/type.TasksListRequestBuilder.html
+    [builder-methods]: requests/tasks_list/type.TasksListRequestBuilder.html#builder-methods
+    [send-sync]: requests/tasks_list/type.TasksListRequestBuilder.html#send-synchronously
+    [send-async]: requests/tasks_list/type.TasksListRequestBuilder.html#send-asynchronously
+    */
+    pub fn tasks_list(&self) -> TasksListRequestBuilder<TSender> {
+        RequestBuilder::initial(self.clone(), TasksListRequestInner { detailed: true })
+    }
+}
+
+impl TasksListRequestInner {
+    fn into_request(self) -> TasksListRequest<'static> {
+        TasksListRequest::new()
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `TasksListRequestBuilder` before sending it.
+*/
+impl<TSender> TasksListRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Set whether or not to return detailed information about each task.
+
+    Tasks are detailed by default.
+    */
+    pub fn detailed(mut self, detailed: bool) -> Self {
+        self.inner.detailed = detailed;
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl TasksListRequestBuilder<SyncSender> {
+    /**
+    Send a `TasksListRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    List the tasks running in the cluster:
+
+    ```no_run
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.tasks_list().send()?;
+
+    for task in response.tasks() {
+        println!("{}: {}", task.task_id(), task.action());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<TasksListResponse> {
+        let detailed = self.inner.detailed;
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .params_fluent(move |p| p.url_param("detailed", detailed))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl TasksListRequestBuilder<AsyncSender> {
+    /**
+    Send a `TasksListRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised task listing response.
+
+    # Examples
+
+    List the tasks running in the cluster:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.tasks_list().send();
+
+    future.and_then(|response| {
+        for task in response.tasks() {
+            println!("{}: {}", task.task_id(), task.action());
+        }
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let detailed = self.inner.detailed;
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .params_fluent(move |p| p.url_param("detailed", detailed))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = TasksListResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = TasksListResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = TasksListResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.tasks_list().inner.into_request();
+
+        assert_eq!("/_tasks", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_detailed() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let builder = client.tasks_list().detailed(false);
+
+        assert!(!builder.inner.detailed);
+    }
+}
@@ -0,0 +1,405 @@
+/*!
+Builders for [validate query requests][docs-validate].
+
+[docs-validate]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+
+use client::requests::endpoints::IndicesValidateQueryRequest;
+use client::requests::params::{
+    Index,
+    Type,
+};
+use client::requests::raw::RawRequestInner;
+use client::requests::{
+    empty_body,
+    DefaultBody,
+    RequestBuilder,
+};
+use client::responses::ValidateQueryResponse;
+use client::sender::{
+    AsyncSender,
+    Sender,
+    SyncSender,
+};
+use client::Client;
+use error::{
+    Error,
+    Result,
+};
+
+/**
+A [validate query request][docs-validate] builder that can be configured before sending.
+
+Call [`Client.validate_query`][Client.validate_query] to get a `ValidateQueryRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-validate]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.validate_query]: ../../struct.Client.html#validate-query-request
+*/
+pub type ValidateQueryRequestBuilder<TSender, TBody> =
+    RequestBuilder<TSender, ValidateQueryRequestInner<TBody>>;
+
+#[doc(hidden)]
+pub struct ValidateQueryRequestInner<TBody> {
+    index: Option<Index<'static>>,
+    ty: Option<Type<'static>>,
+    explain: bool,
+    rewrite: bool,
+    body: TBody,
+}
+
+/**
+# Validate query request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`ValidateQueryRequestBuilder`][ValidateQueryRequestBuilder] with this `Client` that can be configured before sending.
+
+    The query is checked without being executed.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check whether a [Query String][docs-querystring] query is valid, and print the errors if it isn't:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.validate_query()
+                         .index("myindex")
+                         .explain(true)
+                         .body(json!({
+                             "query": {
+                                 "query_string": {
+                                     "query": "title:foo AND"
+                                 }
+                             }
+                         }))
+                         .send()?;
+
+    if !response.valid() {
+        for error in response.errors() {
+            println!("{}", error);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ValidateQueryRequestBuilder]: requests/validate_query/type.ValidateQueryRequestBuilder.html
+    [builder-methods]: requests/validate_query/type.ValidateQueryRequestBuilder.html#builder-methods
+    [send-sync]: requests/validate_query/type.ValidateQueryRequestBuilder.html#send-synchronously
+    [send-async]: requests/validate_query/type.ValidateQueryRequestBuilder.html#send-asynchronously
+    [docs-querystring]: https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-query-string-query.html
+    */
+    pub fn validate_query(&self) -> ValidateQueryRequestBuilder<TSender, DefaultBody> {
+        RequestBuilder::initial(self.clone(), ValidateQueryRequestInner::new(empty_body()))
+    }
+}
+
+impl<TBody> ValidateQueryRequestInner<TBody> {
+    fn new(body: TBody) -> Self {
+        ValidateQueryRequestInner {
+            index: None,
+            ty: None,
+            explain: false,
+            rewrite: false,
+            body: body,
+        }
+    }
+
+    fn into_request(self) -> IndicesValidateQueryRequest<'static, TBody> {
+        let index = self.index.unwrap_or_else(|| "_all".into());
+
+        match self.ty {
+            Some(ty) => IndicesValidateQueryRequest::for_index_ty(index, ty, self.body),
+            None => IndicesValidateQueryRequest::for_index(index, self.body),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ValidateQueryRequestBuilder` before sending it.
+*/
+impl<TSender, TBody> ValidateQueryRequestBuilder<TSender, TBody>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices to validate the query against.
+
+    If no index is specified then `_all` will be used.
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /** Set the types to validate the query against. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = Some(ty.into());
+        self
+    }
+
+    /**
+    Set whether or not to return an explanation of why the query is invalid.
+
+    Explanations aren't returned by default.
+    */
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.inner.explain = explain;
+        self
+    }
+
+    /**
+    Set whether or not to return the query as it will actually be executed.
+
+    Rewritten queries aren't returned by default.
+    */
+    pub fn rewrite(mut self, rewrite: bool) -> Self {
+        self.inner.rewrite = rewrite;
+        self
+    }
+
+    /**
+    Set the body for the validate query request.
+
+    If no body is specified then an empty query will be used.
+    */
+    pub fn body<TNewBody>(self, body: TNewBody) -> ValidateQueryRequestBuilder<TSender, TNewBody>
+    where
+        TNewBody: Into<TSender::Body>,
+    {
+        RequestBuilder::new(
+            self.client,
+            self.params_builder,
+            ValidateQueryRequestInner {
+                body: body,
+                index: self.inner.index,
+                ty: self.inner.ty,
+                explain: self.inner.explain,
+                rewrite: self.inner.rewrite,
+            },
+        )
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TBody> ValidateQueryRequestBuilder<SyncSender, TBody>
+where
+    TBody: Into<<SyncSender as Sender>::Body> + 'static,
+{
+    /**
+    Send a `ValidateQueryRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Check whether a query is valid:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.validate_query()
+                         .index("myindex")
+                         .body(json!({
+                             "query": {
+                                 "match_all": {}
+                             }
+                         }))
+                         .send()?;
+
+    assert!(response.valid());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<ValidateQueryResponse> {
+        let (explain, rewrite) = (self.inner.explain, self.inner.rewrite);
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .params_fluent(move |p| {
+                p.url_param("explain", explain).url_param("rewrite", rewrite)
+            })
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TBody> ValidateQueryRequestBuilder<AsyncSender, TBody>
+where
+    TBody: Into<<AsyncSender as Sender>::Body> + 'static,
+{
+    /**
+    Send a `ValidateQueryRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised validate query response.
+
+    # Examples
+
+    Check whether a query is valid:
+
+    ```no_run
+    # extern crate tokio;
+    # extern crate futures;
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.validate_query()
+                       .index("myindex")
+                       .body(json!({
+                           "query": {
+                               "match_all": {}
+                           }
+                       }))
+                       .send();
+
+    future.and_then(|response| {
+        assert!(response.valid());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (explain, rewrite) = (self.inner.explain, self.inner.rewrite);
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .params_fluent(move |p| {
+                    p.url_param("explain", explain).url_param("rewrite", rewrite)
+                })
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<Future<Item = ValidateQueryResponse, Error = Error>>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = ValidateQueryResponse, Error = Error> + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = ValidateQueryResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.validate_query().inner.into_request();
+
+        assert_eq!("/_all/_validate/query", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .validate_query()
+            .index("new-idx")
+            .inner
+            .into_request();
+
+        assert_eq!("/new-idx/_validate/query", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .validate_query()
+            .index("new-idx")
+            .ty("new-ty")
+            .inner
+            .into_request();
+
+        assert_eq!("/new-idx/new-ty/_validate/query", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_body() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.validate_query().body("{}").inner.into_request();
+
+        assert_eq!("{}", req.body);
+    }
+
+    #[test]
+    fn specify_flags() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let builder = client.validate_query().explain(true).rewrite(true);
+
+        assert!(builder.inner.explain);
+        assert!(builder.inner.rewrite);
+    }
+}
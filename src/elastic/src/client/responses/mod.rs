@@ -19,15 +19,25 @@ pub use self::sync::*;
 pub use elastic_responses::{
     BulkErrorsResponse,
     BulkResponse,
+    CatNodesResponse,
+    CatShardsResponse,
+    ClusterSettingsResponse,
     CommandResponse,
     DeleteResponse,
     GetResponse,
     IndexResponse,
     IndicesExistsResponse,
+    NodesHotThreadsResponse,
     PingResponse,
     SearchResponse,
+    ShardFailure,
+    ShardState,
     Shards,
+    SnapshotState,
+    SnapshotStatusResponse,
+    TasksListResponse,
     UpdateResponse,
+    ValidateQueryResponse,
 };
 
 pub use elastic_responses::bulk;
@@ -41,15 +51,25 @@ pub mod prelude {
         AsyncResponseBuilder,
         BulkErrorsResponse,
         BulkResponse,
+        CatNodesResponse,
+        CatShardsResponse,
+        ClusterSettingsResponse,
         CommandResponse,
         DeleteResponse,
         GetResponse,
         IndexResponse,
         IndicesExistsResponse,
+        NodesHotThreadsResponse,
         PingResponse,
         SearchResponse,
+        ShardFailure,
+        ShardState,
         Shards,
+        SnapshotState,
+        SnapshotStatusResponse,
         SyncResponseBuilder,
+        TasksListResponse,
         UpdateResponse,
+        ValidateQueryResponse,
     };
 }
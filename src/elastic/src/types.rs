@@ -44,10 +44,12 @@ The following table illustrates the types provided by `elastic`:
  `byte`              | `i8`                        | `std`     | [`Byte<M>`][number-mod]                                  | -
  `float`             | `f32`                       | `std`     | [`Float<M>`][number-mod]                                 | -
  `double`            | `f64`                       | `std`     | [`Double<M>`][number-mod]                                | -
+ `scaled_float`      | -                           | -         | [`ScaledFloat<M>`][number-mod]                           | -
  `keyword`           | -                           | -         | [`Keyword<M>`][string-mod]                               | -
  `text`              | `String`                    | `std`     | [`Text<M>`][string-mod]                                  | -
  `boolean`           | `bool`                      | `std`     | [`Boolean<M>`][boolean-mod]                              | -
- `ip`                | `Ipv4Addr`                  | `std`     | [`Ip<M>`][ip-mod]                                        | -
+ `binary`            | -                           | -         | [`Binary<M>`][binary-mod]                                | -
+ `ip`                | `IpAddr`                    | `std`     | [`Ip<M>`][ip-mod]                                        | -
  `date`              | `DateTime<UTC>`             | `chrono`  | [`Date<M>`][date-mod]                                    | `DateFormat`
  `geo_point`         | `Point`                     | `geo`     | [`GeoPoint<M>`][geopoint-mod]                            | `GeoPointFormat`
  `geo_shape`         | -                           | `geojson` | [`GeoShape<M>`][geoshape-mod]                            | -
@@ -258,6 +260,7 @@ Serialising `MyType`s mapping will produce the following json:
 [number-mod]: number/index.html
 [string-mod]: string/index.html
 [boolean-mod]: boolean/index.html
+[binary-mod]: binary/index.html
 [ip-mod]: ip/index.html
 [date-mod]: date/index.html
 [geopoint-mod]: geo/point/index.html
@@ -265,6 +268,7 @@ Serialising `MyType`s mapping will produce the following json:
 */
 
 pub use elastic_types::{
+    binary,
     boolean,
     date,
     document,
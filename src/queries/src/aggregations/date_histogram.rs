@@ -1,5 +1,8 @@
+use super::super::Values;
 use super::BucketAggregation;
 use super::EsAggregation;
+use serde::Serialize;
+use serde_json;
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -14,6 +17,22 @@ pub struct DateHistogramFields {
     pub field: String,
     pub interval: String,
     pub min_doc_count: u64,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub missing: Option<Values>,
+}
+
+impl DateHistogramAggregation {
+    /// Set the date to use for documents that don't have the field.
+    ///
+    /// The value is serialized using its own `Serialize` implementation,
+    /// so an `elastic_types` `Date` is written in the format of its field.
+    pub fn set_missing<T>(&mut self, missing: &T) -> Result<(), serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.date_histogram.missing = Some(Values::from_serialize(missing)?);
+        Ok(())
+    }
 }
 
 impl BucketAggregation for DateHistogramAggregation {
@@ -64,4 +83,59 @@ mod tests {
         }"#;
         let _s: DateHistogramAggregation = serde_json::from_str(j).unwrap();
     }
+
+    #[test]
+    fn date_histo_aggs_missing() {
+        let j = r#"{
+          "date_histogram": {
+            "field": "@timestamp",
+            "interval": "1d",
+            "min_doc_count": 0
+          }
+        }"#;
+        let mut s: DateHistogramAggregation = serde_json::from_str(j).unwrap();
+
+        s.set_missing(&1483228800000i64).unwrap();
+
+        let ser = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(
+            r#"{"date_histogram":{"field":"@timestamp","interval":"1d","min_doc_count":0,"missing":1483228800000}}"#,
+            ser
+        );
+    }
+
+    #[test]
+    fn date_histo_aggs_missing_date() {
+        use elastic_types::prelude::*;
+
+        let j = r#"{
+          "date_histogram": {
+            "field": "@timestamp",
+            "interval": "1d",
+            "min_doc_count": 0
+          }
+        }"#;
+        let mut s: DateHistogramAggregation = serde_json::from_str(j).unwrap();
+
+        let missing: Date<DefaultDateMapping<EpochMillis>> = Date::build(2017, 1, 1, 0, 0, 0, 0);
+        s.set_missing(&missing).unwrap();
+
+        let ser = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(
+            r#"{"date_histogram":{"field":"@timestamp","interval":"1d","min_doc_count":0,"missing":1483228800000}}"#,
+            ser
+        );
+
+        let missing: Date<DefaultDateMapping<BasicDateTime>> = Date::build(2017, 1, 1, 0, 0, 0, 0);
+        s.set_missing(&missing).unwrap();
+
+        let ser = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(
+            r#"{"date_histogram":{"field":"@timestamp","interval":"1d","min_doc_count":0,"missing":"20170101T000000.000Z"}}"#,
+            ser
+        );
+    }
 }
@@ -1,6 +1,9 @@
 use super::super::filters::common::*;
+use super::super::Values;
 use super::BucketAggregation;
 use super::EsAggregation;
+use serde::Serialize;
+use serde_json;
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -15,6 +18,22 @@ pub struct TermsAggFields {
     pub field: String,
     pub size: u64,
     pub order: Option<FieldAndValue>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub missing: Option<Values>,
+}
+
+impl TermAggregation {
+    /// Set the value to use for documents that don't have the field.
+    ///
+    /// The value is serialized using its own `Serialize` implementation,
+    /// so an `elastic_types` `Date` is written in the format of its field.
+    pub fn set_missing<T>(&mut self, missing: &T) -> Result<(), serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.terms.missing = Some(Values::from_serialize(missing)?);
+        Ok(())
+    }
 }
 
 impl BucketAggregation for TermAggregation {
@@ -57,4 +76,64 @@ mod tests {
                     }"#;
         let _s: TermAggregation = serde_json::from_str(j).unwrap();
     }
+
+    #[test]
+    fn terms_aggs_missing() {
+        let j = r#"{
+                      "terms": {
+                        "field": "timestamp",
+                        "size": 10,
+                        "order": null
+                      }
+                    }"#;
+        let mut s: TermAggregation = serde_json::from_str(j).unwrap();
+
+        s.set_missing("2017-01-01T00:00:00Z").unwrap();
+
+        let ser = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(
+            r#"{"terms":{"field":"timestamp","size":10,"order":null,"missing":"2017-01-01T00:00:00Z"}}"#,
+            ser
+        );
+    }
+
+    #[test]
+    fn terms_aggs_missing_date() {
+        use elastic_types::prelude::*;
+
+        let j = r#"{
+                      "terms": {
+                        "field": "timestamp",
+                        "size": 10,
+                        "order": null
+                      }
+                    }"#;
+        let mut s: TermAggregation = serde_json::from_str(j).unwrap();
+
+        let missing: Date<DefaultDateMapping<BasicDateTime>> = Date::build(2017, 1, 1, 0, 0, 0, 0);
+        s.set_missing(&missing).unwrap();
+
+        let ser = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(
+            r#"{"terms":{"field":"timestamp","size":10,"order":null,"missing":"20170101T000000.000Z"}}"#,
+            ser
+        );
+    }
+
+    #[test]
+    fn terms_aggs_missing_not_scalar() {
+        let j = r#"{
+                      "terms": {
+                        "field": "timestamp",
+                        "size": 10,
+                        "order": null
+                      }
+                    }"#;
+        let mut s: TermAggregation = serde_json::from_str(j).unwrap();
+
+        assert!(s.set_missing(&vec![1, 2]).is_err());
+        assert!(s.terms.missing.is_none());
+    }
 }
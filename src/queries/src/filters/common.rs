@@ -6,6 +6,7 @@ use serde::ser::{
     SerializeMap,
     Serializer,
 };
+use std::cmp::Ordering;
 use std::fmt;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialOrd, PartialEq, Ord, Eq)]
@@ -18,6 +19,44 @@ pub enum EsDateFormat {
     __Nonexhaustive,
 }
 
+/// A floating point number that can be used in ordered filters.
+///
+/// `NaN` is equal to itself and ordered after every other number,
+/// so floats have a total order.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Float(pub f64);
+
+impl From<f64> for Float {
+    fn from(f: f64) -> Self {
+        Float(f)
+    }
+}
+
+impl PartialEq for Float {
+    fn eq(&self, other: &Float) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Float {}
+
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Float) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Float {
+    fn cmp(&self, other: &Float) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Field {
     pub field: Values,
@@ -49,6 +88,53 @@ impl Serialize for FieldAndValue {
     }
 }
 
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct FieldAndValues {
+    pub field: String,
+    pub values: Vec<Values>,
+}
+
+impl<'de> serde::Deserialize<'de> for FieldAndValues {
+    fn deserialize<D>(deserializer: D) -> Result<FieldAndValues, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(FieldAndValuesVisitor)
+    }
+}
+
+impl Serialize for FieldAndValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.field, &self.values)?;
+        map.end()
+    }
+}
+
+pub(crate) struct FieldAndValuesVisitor;
+
+impl<'de> Visitor<'de> for FieldAndValuesVisitor {
+    type Value = FieldAndValues;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a key with an array of values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+        let field = map.next_key()?.ok_or(A::Error::custom("expected field"))?;
+        let values: Vec<Values> = map.next_value()?;
+
+        Ok(FieldAndValues { field, values })
+    }
+}
+
 pub(crate) struct FieldAndValueVisitor;
 
 impl<'de> Visitor<'de> for FieldAndValueVisitor {
@@ -87,4 +173,29 @@ mod tests {
         let j = serde_json::to_string(&s).unwrap();
         assert_eq!(o, j);
     }
+
+    #[test]
+    fn float_nan_is_ordered_last() {
+        use std::f64;
+
+        let mut floats = vec![Float(f64::NAN), Float(1.0), Float(f64::INFINITY), Float(-1.0)];
+        floats.sort();
+
+        assert_eq!(Float(-1.0), floats[0]);
+        assert_eq!(Float(1.0), floats[1]);
+        assert_eq!(Float(f64::INFINITY), floats[2]);
+        assert!(floats[3].0.is_nan());
+
+        assert_eq!(Float(f64::NAN), Float(f64::NAN));
+        assert!(Float(1.0) < Float(f64::NAN));
+        assert!(Float(f64::NAN) != Float(1.0));
+    }
+
+    #[test]
+    fn kvs_parse_and_serialise() {
+        let o = r#"{"user":["kimchy","elasticsearch"]}"#;
+        let s: FieldAndValues = serde_json::from_str(o).unwrap();
+        let j = serde_json::to_string(&s).unwrap();
+        assert_eq!(o, j);
+    }
 }
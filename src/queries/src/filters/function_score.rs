@@ -0,0 +1,908 @@
+use super::super::{
+    QueryField,
+    Values,
+};
+use super::common::Float;
+use super::Filters;
+use serde;
+use serde::de::Visitor;
+use serde::ser::{
+    Serialize,
+    SerializeMap,
+    Serializer,
+};
+use serde_json;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A `function_score` query that adjusts the score of documents matched by a base query.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct FunctionScoreFilter {
+    pub function_score: FunctionScore,
+}
+
+impl FunctionScoreFilter {
+    pub fn new(function_score: FunctionScore) -> FunctionScoreFilter {
+        FunctionScoreFilter {
+            function_score: function_score,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct FunctionScore {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub query: Option<QueryField>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub functions: Vec<ScoreFunction>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub score_mode: Option<ScoreMode>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub boost_mode: Option<BoostMode>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_boost: Option<Float>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub min_score: Option<Float>,
+}
+
+impl FunctionScore {
+    pub fn new() -> FunctionScore {
+        FunctionScore::default()
+    }
+
+    /// Set the base query whose documents are scored.
+    ///
+    /// If no query is set then all documents are matched.
+    pub fn query(mut self, query: QueryField) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Add a scoring function.
+    pub fn function<F>(mut self, function: F) -> Self
+    where
+        F: Into<ScoreFunction>,
+    {
+        self.functions.push(function.into());
+        self
+    }
+
+    /// Set how the scores of the functions are combined.
+    pub fn score_mode(mut self, score_mode: ScoreMode) -> Self {
+        self.score_mode = Some(score_mode);
+        self
+    }
+
+    /// Set how the combined function score is combined with the query score.
+    pub fn boost_mode(mut self, boost_mode: BoostMode) -> Self {
+        self.boost_mode = Some(boost_mode);
+        self
+    }
+
+    /// Set the maximum score the functions can produce.
+    pub fn max_boost(mut self, max_boost: f64) -> Self {
+        self.max_boost = Some(Float(max_boost));
+        self
+    }
+
+    /// Exclude documents that don't reach the given score.
+    pub fn min_score(mut self, min_score: f64) -> Self {
+        self.min_score = Some(Float(min_score));
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoreMode {
+    Multiply,
+    Sum,
+    Avg,
+    First,
+    Max,
+    Min,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BoostMode {
+    Multiply,
+    Replace,
+    Sum,
+    Avg,
+    Max,
+    Min,
+}
+
+/// A single scoring function, with an optional filter and weight.
+///
+/// A function with only a weight multiplies the score of documents matching its filter.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ScoreFunction {
+    pub filter: Option<Filters>,
+    pub weight: Option<Float>,
+    pub function: Option<ScoreFunctionKind>,
+}
+
+impl ScoreFunction {
+    pub fn new<F>(function: F) -> ScoreFunction
+    where
+        F: Into<ScoreFunctionKind>,
+    {
+        ScoreFunction {
+            filter: None,
+            weight: None,
+            function: Some(function.into()),
+        }
+    }
+
+    /// Create a function that only applies a weight.
+    pub fn weighted(weight: f64) -> ScoreFunction {
+        ScoreFunction {
+            filter: None,
+            weight: Some(Float(weight)),
+            function: None,
+        }
+    }
+
+    /// Only apply this function to documents matching the given filter.
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Into<Filters>,
+    {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Multiply the score of this function by the given weight.
+    pub fn weight(mut self, weight: f64) -> Self {
+        self.weight = Some(Float(weight));
+        self
+    }
+}
+
+impl From<ScoreFunctionKind> for ScoreFunction {
+    fn from(function: ScoreFunctionKind) -> Self {
+        ScoreFunction::new(function)
+    }
+}
+
+impl From<FieldValueFactor> for ScoreFunction {
+    fn from(function: FieldValueFactor) -> Self {
+        ScoreFunction::new(function)
+    }
+}
+
+impl From<DecayFunction> for ScoreFunction {
+    fn from(function: DecayFunction) -> Self {
+        ScoreFunction::new(function)
+    }
+}
+
+impl From<ScriptScore> for ScoreFunction {
+    fn from(function: ScriptScore) -> Self {
+        ScoreFunction::new(function)
+    }
+}
+
+impl Serialize for ScoreFunction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+
+        if let Some(ref filter) = self.filter {
+            map.serialize_entry("filter", filter)?;
+        }
+
+        if let Some(ref weight) = self.weight {
+            map.serialize_entry("weight", weight)?;
+        }
+
+        match self.function {
+            Some(ScoreFunctionKind::FieldValueFactor(ref f)) => {
+                map.serialize_entry("field_value_factor", f)?
+            }
+            Some(ScoreFunctionKind::Decay(ref f)) => {
+                map.serialize_entry(f.kind.as_str(), &DecayFieldRef(f))?
+            }
+            Some(ScoreFunctionKind::ScriptScore(ref f)) => map.serialize_entry("script_score", f)?,
+            None => (),
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ScoreFunction {
+    fn deserialize<D>(deserializer: D) -> Result<ScoreFunction, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ScoreFunctionVisitor)
+    }
+}
+
+pub(crate) struct ScoreFunctionVisitor;
+
+impl<'de> Visitor<'de> for ScoreFunctionVisitor {
+    type Value = ScoreFunction;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a score function")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut function = ScoreFunction {
+            filter: None,
+            weight: None,
+            function: None,
+        };
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "filter" => function.filter = Some(map.next_value()?),
+                "weight" => function.weight = Some(map.next_value()?),
+                "field_value_factor" => {
+                    function.function = Some(ScoreFunctionKind::FieldValueFactor(map.next_value()?))
+                }
+                "script_score" => {
+                    function.function = Some(ScoreFunctionKind::ScriptScore(map.next_value()?))
+                }
+                kind => match kind.parse::<DecayKind>() {
+                    Ok(kind) => {
+                        let DecayField(field, params, multi_value_mode) = map.next_value()?;
+
+                        function.function = Some(ScoreFunctionKind::Decay(DecayFunction {
+                            kind: kind,
+                            field: field,
+                            params: params,
+                            multi_value_mode: multi_value_mode,
+                        }))
+                    }
+                    Err(_) => {
+                        return Err(A::Error::custom(format!(
+                            "unknown score function '{}'",
+                            kind
+                        )))
+                    }
+                },
+            }
+        }
+
+        Ok(function)
+    }
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum ScoreFunctionKind {
+    FieldValueFactor(FieldValueFactor),
+    Decay(DecayFunction),
+    ScriptScore(ScriptScore),
+}
+
+impl From<FieldValueFactor> for ScoreFunctionKind {
+    fn from(f: FieldValueFactor) -> Self {
+        ScoreFunctionKind::FieldValueFactor(f)
+    }
+}
+
+impl From<DecayFunction> for ScoreFunctionKind {
+    fn from(f: DecayFunction) -> Self {
+        ScoreFunctionKind::Decay(f)
+    }
+}
+
+impl From<ScriptScore> for ScoreFunctionKind {
+    fn from(f: ScriptScore) -> Self {
+        ScoreFunctionKind::ScriptScore(f)
+    }
+}
+
+/// Score documents using the value of a numeric field.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct FieldValueFactor {
+    pub field: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub factor: Option<Float>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub modifier: Option<FieldValueFactorModifier>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub missing: Option<Float>,
+}
+
+impl FieldValueFactor {
+    pub fn new(field: &str) -> FieldValueFactor {
+        FieldValueFactor {
+            field: field.to_string(),
+            factor: None,
+            modifier: None,
+            missing: None,
+        }
+    }
+
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = Some(Float(factor));
+        self
+    }
+
+    pub fn modifier(mut self, modifier: FieldValueFactorModifier) -> Self {
+        self.modifier = Some(modifier);
+        self
+    }
+
+    /// Set the value to use for documents that don't have the field.
+    pub fn missing(mut self, missing: f64) -> Self {
+        self.missing = Some(Float(missing));
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldValueFactorModifier {
+    None,
+    Log,
+    Log1p,
+    Log2p,
+    Ln,
+    Ln1p,
+    Ln2p,
+    Square,
+    Sqrt,
+    Reciprocal,
+}
+
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum DecayKind {
+    Gauss,
+    Linear,
+    Exp,
+}
+
+impl DecayKind {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            DecayKind::Gauss => "gauss",
+            DecayKind::Linear => "linear",
+            DecayKind::Exp => "exp",
+        }
+    }
+}
+
+impl ::std::str::FromStr for DecayKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<DecayKind, ()> {
+        match s {
+            "gauss" => Ok(DecayKind::Gauss),
+            "linear" => Ok(DecayKind::Linear),
+            "exp" => Ok(DecayKind::Exp),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Score documents by how far the value of a field is from an origin.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct DecayFunction {
+    pub kind: DecayKind,
+    pub field: String,
+    pub params: DecayParams,
+    pub multi_value_mode: Option<MultiValueMode>,
+}
+
+impl DecayFunction {
+    /// Create a decay function for a field.
+    ///
+    /// The `scale` is the distance from the origin where the score is reduced by the `decay`.
+    /// For a date field it's a time unit like `10d`, for a geo field it's a distance like `2km`,
+    /// and for a numeric field it's a number like `0.5`.
+    pub fn new<V>(kind: DecayKind, field: &str, scale: V) -> DecayFunction
+    where
+        V: Into<Values>,
+    {
+        DecayFunction {
+            kind: kind,
+            field: field.to_string(),
+            params: DecayParams {
+                origin: None,
+                scale: scale.into(),
+                offset: None,
+                decay: None,
+            },
+            multi_value_mode: None,
+        }
+    }
+
+    /// Set the origin to calculate distances from.
+    ///
+    /// Date fields use `now` if no origin is set.
+    pub fn origin<V>(mut self, origin: V) -> Self
+    where
+        V: Into<Values>,
+    {
+        self.params.origin = Some(origin.into());
+        self
+    }
+
+    /// Set the origin from a typed value.
+    ///
+    /// The value is serialized using its own `Serialize` implementation,
+    /// so an `elastic_types` `Date` or `DateExpr` is written in the format of its field.
+    pub fn set_origin<T>(&mut self, origin: &T) -> Result<(), serde_json::Error>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        self.params.origin = Some(Values::from_serialize(origin)?);
+        Ok(())
+    }
+
+    /// Set the scale from a typed value.
+    ///
+    /// The value is serialized using its own `Serialize` implementation,
+    /// like `set_origin`.
+    pub fn set_scale<T>(&mut self, scale: &T) -> Result<(), serde_json::Error>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        self.params.scale = Values::from_serialize(scale)?;
+        Ok(())
+    }
+
+    /// Only start decaying the score for values further than the offset from the origin.
+    pub fn offset<V>(mut self, offset: V) -> Self
+    where
+        V: Into<Values>,
+    {
+        self.params.offset = Some(offset.into());
+        self
+    }
+
+    /// Set the offset from a typed value.
+    ///
+    /// The value is serialized using its own `Serialize` implementation,
+    /// like `set_origin`.
+    pub fn set_offset<T>(&mut self, offset: &T) -> Result<(), serde_json::Error>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        self.params.offset = Some(Values::from_serialize(offset)?);
+        Ok(())
+    }
+
+    /// Set the score for values at `scale` from the origin.
+    pub fn decay(mut self, decay: f64) -> Self {
+        self.params.decay = Some(Float(decay));
+        self
+    }
+
+    /// Set which distance is used when the field has multiple values.
+    pub fn multi_value_mode(mut self, multi_value_mode: MultiValueMode) -> Self {
+        self.multi_value_mode = Some(multi_value_mode);
+        self
+    }
+}
+
+/// Which distance a decay function uses when the field has multiple values.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MultiValueMode {
+    Min,
+    Max,
+    Avg,
+    Sum,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DecayParams {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub origin: Option<Values>,
+    pub scale: Values,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub offset: Option<Values>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub decay: Option<Float>,
+}
+
+struct DecayFieldRef<'a>(&'a DecayFunction);
+
+impl<'a> Serialize for DecayFieldRef<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(&self.0.field, &self.0.params)?;
+
+        if let Some(ref multi_value_mode) = self.0.multi_value_mode {
+            map.serialize_entry("multi_value_mode", multi_value_mode)?;
+        }
+
+        map.end()
+    }
+}
+
+struct DecayField(String, DecayParams, Option<MultiValueMode>);
+
+impl<'de> serde::Deserialize<'de> for DecayField {
+    fn deserialize<D>(deserializer: D) -> Result<DecayField, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DecayFieldVisitor)
+    }
+}
+
+struct DecayFieldVisitor;
+
+impl<'de> Visitor<'de> for DecayFieldVisitor {
+    type Value = DecayField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a field with decay parameters")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let (mut field, mut multi_value_mode) = (None, None);
+
+        // Any key that isn't a known option is the field with the decay parameters
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "multi_value_mode" => multi_value_mode = Some(map.next_value()?),
+                _ => {
+                    if let Some((ref field, _)) = field {
+                        return Err(A::Error::custom(format!(
+                            "expected a single field, but found `{}` and `{}`",
+                            field, key
+                        )));
+                    }
+
+                    let params: DecayParams = map.next_value()?;
+                    field = Some((key, params));
+                }
+            }
+        }
+
+        let (field, params) = field.ok_or(A::Error::custom("expected field"))?;
+
+        Ok(DecayField(field, params, multi_value_mode))
+    }
+}
+
+/// Score documents using a script.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ScriptScore {
+    pub script: ScoreScript,
+}
+
+impl ScriptScore {
+    pub fn new(source: &str) -> ScriptScore {
+        ScriptScore {
+            script: ScoreScript {
+                inline: source.to_string(),
+                lang: None,
+                params: None,
+            },
+        }
+    }
+
+    pub fn lang(mut self, lang: &str) -> Self {
+        self.script.lang = Some(lang.to_string());
+        self
+    }
+
+    pub fn param<V>(mut self, key: &str, value: V) -> Self
+    where
+        V: Into<Values>,
+    {
+        self.script
+            .params
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.to_string(), value.into());
+        self
+    }
+}
+
+/// The source, language and parameters of a scoring script.
+///
+/// The source is serialized as `inline`, but either `inline` or `source` is accepted when deserializing,
+/// as well as a script that's just a string of source.
+#[derive(Clone, Debug, Serialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ScoreScript {
+    pub inline: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub lang: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub params: Option<BTreeMap<String, Values>>,
+}
+
+impl<'de> serde::Deserialize<'de> for ScoreScript {
+    fn deserialize<D>(deserializer: D) -> Result<ScoreScript, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ScoreScriptVisitor)
+    }
+}
+
+const SCORE_SCRIPT_FIELDS: &'static [&'static str] = &["inline", "source", "lang", "params"];
+
+struct ScoreScriptVisitor;
+
+impl<'de> Visitor<'de> for ScoreScriptVisitor {
+    type Value = ScoreScript;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a script source or an object with a script source")
+    }
+
+    fn visit_str<E>(self, source: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(ScoreScript {
+            inline: source.to_string(),
+            lang: None,
+            params: None,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let (mut inline, mut lang, mut params) = (None, None, None);
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "inline" | "source" => {
+                    if inline.is_some() {
+                        return Err(A::Error::custom("expected only one of `inline` or `source`"));
+                    }
+
+                    inline = Some(map.next_value()?)
+                }
+                "lang" => lang = map.next_value()?,
+                "params" => params = map.next_value()?,
+                _ => return Err(A::Error::unknown_field(&key, SCORE_SCRIPT_FIELDS)),
+            }
+        }
+
+        let inline = inline.ok_or(A::Error::missing_field("source"))?;
+
+        Ok(ScoreScript {
+            inline: inline,
+            lang: lang,
+            params: params,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::term::TermFilter;
+    use super::*;
+    use serde_json;
+    use BoolBuilder;
+    use QueryFieldBuilder;
+
+    #[test]
+    fn function_score_filter_new() {
+        let query = QueryFieldBuilder::default()
+            .bool(
+                BoolBuilder::default()
+                    .must(Some(vec![
+                        TermFilter::new("title".to_string(), "elasticsearch".into()).into(),
+                    ]))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let f = FunctionScoreFilter::new(
+            FunctionScore::new()
+                .query(query)
+                .function(
+                    FieldValueFactor::new("likes")
+                        .factor(1.2)
+                        .modifier(FieldValueFactorModifier::Sqrt)
+                        .missing(1.0),
+                )
+                .function(
+                    DecayFunction::new(DecayKind::Gauss, "published", "10d")
+                        .origin("now")
+                        .offset("5d")
+                        .decay(0.5),
+                )
+                .function(ScriptScore::new("_score * doc['boost'].value").param("factor", 2))
+                .function(
+                    ScoreFunction::weighted(2.0)
+                        .filter(TermFilter::new("featured".to_string(), true.into())),
+                )
+                .score_mode(ScoreMode::Sum)
+                .boost_mode(BoostMode::Multiply)
+                .max_boost(10.0),
+        );
+
+        let expected: serde_json::Value = serde_json::from_str(
+            r#"{
+            "function_score": {
+                "query": {
+                    "bool": {
+                        "must": [ { "term": { "title": "elasticsearch" } } ]
+                    }
+                },
+                "functions": [
+                    {
+                        "field_value_factor": {
+                            "field": "likes",
+                            "factor": 1.2,
+                            "modifier": "sqrt",
+                            "missing": 1.0
+                        }
+                    },
+                    {
+                        "gauss": {
+                            "published": {
+                                "origin": "now",
+                                "scale": "10d",
+                                "offset": "5d",
+                                "decay": 0.5
+                            }
+                        }
+                    },
+                    {
+                        "script_score": {
+                            "script": {
+                                "inline": "_score * doc['boost'].value",
+                                "params": { "factor": 2 }
+                            }
+                        }
+                    },
+                    {
+                        "filter": { "term": { "featured": true } },
+                        "weight": 2.0
+                    }
+                ],
+                "score_mode": "sum",
+                "boost_mode": "multiply",
+                "max_boost": 10.0
+            }
+        }"#,
+        ).unwrap();
+
+        assert_eq!(expected, serde_json::to_value(&f).unwrap());
+
+        let s: FunctionScoreFilter = serde_json::from_value(expected).unwrap();
+        assert_eq!(f, s);
+    }
+
+    #[test]
+    fn function_score_filter() {
+        let j = r#"{
+            "function_score": {
+                "functions": [
+                    { "exp": { "price": { "origin": 0, "scale": 20 } } },
+                    { "linear": { "location": { "origin": "11,12", "scale": "2km" } }, "weight": 3 }
+                ],
+                "boost_mode": "replace"
+            }
+        }"#;
+        let s: FunctionScoreFilter = serde_json::from_str(j).unwrap();
+
+        assert_eq!(2, s.function_score.functions.len());
+        assert_eq!(Some(BoostMode::Replace), s.function_score.boost_mode);
+    }
+
+    #[test]
+    fn function_score_unknown_function() {
+        let j = r#"{ "function_score": { "functions": [ { "random": {} } ] } }"#;
+
+        assert!(serde_json::from_str::<FunctionScoreFilter>(j).is_err());
+    }
+
+    #[derive(Serialize)]
+    struct Formatted(&'static str);
+
+    #[test]
+    fn decay_function_typed_origin() {
+        let mut f = DecayFunction::new(DecayKind::Exp, "timestamp", "1d");
+        f.set_origin(&Formatted("20150513")).unwrap();
+
+        assert_eq!(Some(Values::from("20150513")), f.params.origin);
+    }
+
+    #[test]
+    fn decay_function_typed_scale_and_offset() {
+        let mut f = DecayFunction::new(DecayKind::Exp, "timestamp", "1d");
+        f.set_scale(&Formatted("10d")).unwrap();
+        f.set_offset(&Formatted("2d")).unwrap();
+
+        assert_eq!(Values::from("10d"), f.params.scale);
+        assert_eq!(Some(Values::from("2d")), f.params.offset);
+    }
+
+    #[test]
+    fn decay_function_float() {
+        let f = DecayFunction::new(DecayKind::Gauss, "price", 0.5).origin(9.99);
+
+        let j = r#"{"gauss":{"price":{"origin":9.99,"scale":0.5}}}"#;
+        assert_eq!(j, serde_json::to_string(&ScoreFunction::from(f.clone())).unwrap());
+
+        let s: ScoreFunction = serde_json::from_str(j).unwrap();
+        assert_eq!(ScoreFunction::from(f), s);
+
+        let mut f = DecayFunction::new(DecayKind::Gauss, "price", 0.5);
+        f.set_origin(&9.99).unwrap();
+
+        assert_eq!(Some(Values::from(9.99)), f.params.origin);
+    }
+
+    #[test]
+    fn decay_function_multi_value_mode() {
+        let f = DecayFunction::new(DecayKind::Gauss, "location", "2km")
+            .origin("11,12")
+            .multi_value_mode(MultiValueMode::Avg);
+
+        let j = r#"{"gauss":{"location":{"origin":"11,12","scale":"2km"},"multi_value_mode":"avg"}}"#;
+        assert_eq!(j, serde_json::to_string(&ScoreFunction::from(f.clone())).unwrap());
+
+        let j = r#"{ "gauss": { "multi_value_mode": "avg", "location": { "origin": "11,12", "scale": "2km" } } }"#;
+        let s: ScoreFunction = serde_json::from_str(j).unwrap();
+        assert_eq!(ScoreFunction::from(f), s);
+    }
+
+    #[test]
+    fn decay_function_unknown_keys() {
+        let j = r#"{ "gauss": { "location": { "scale": "2km" }, "other": { "scale": "2km" } } }"#;
+        assert!(serde_json::from_str::<ScoreFunction>(j).is_err());
+
+        let j = r#"{ "gauss": { "location": { "scale": "2km", "unknown": 1 } } }"#;
+        assert!(serde_json::from_str::<ScoreFunction>(j).is_err());
+
+        let j = r#"{ "gauss": { "multi_value_mode": "avg" } }"#;
+        assert!(serde_json::from_str::<ScoreFunction>(j).is_err());
+    }
+
+    #[test]
+    fn script_score_source() {
+        let expected = ScriptScore::new("_score * 2").lang("painless");
+
+        let j = r#"{ "script": { "source": "_score * 2", "lang": "painless" } }"#;
+        assert_eq!(expected, serde_json::from_str(j).unwrap());
+
+        let j = r#"{ "script": { "inline": "_score * 2", "lang": "painless" } }"#;
+        assert_eq!(expected, serde_json::from_str(j).unwrap());
+
+        let j = r#"{ "script": "_score * 2" }"#;
+        assert_eq!(ScriptScore::new("_score * 2"), serde_json::from_str(j).unwrap());
+    }
+
+    #[test]
+    fn script_score_invalid() {
+        let j = r#"{ "script": { "source": "_score", "inline": "_score" } }"#;
+        assert!(serde_json::from_str::<ScriptScore>(j).is_err());
+
+        let j = r#"{ "script": { "lang": "painless" } }"#;
+        assert!(serde_json::from_str::<ScriptScore>(j).is_err());
+
+        let j = r#"{ "script": { "source": "_score", "unknown": 1 } }"#;
+        assert!(serde_json::from_str::<ScriptScore>(j).is_err());
+    }
+}
@@ -0,0 +1,453 @@
+use super::common::Float;
+use serde;
+use serde::de::Visitor;
+use serde::ser::{
+    Serialize,
+    SerializeMap,
+    Serializer,
+};
+use serde_json;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+const GEOHASH_ALPHABET: &'static [u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+fn cmp_f64(a: f64, b: f64) -> Ordering {
+    Float(a).cmp(&Float(b))
+}
+
+/// A geo point used in geo queries.
+///
+/// Elasticsearch accepts points as an object, an array, a `"lat,lon"` string or a geohash.
+/// All of these are parsed into the same latitude and longitude,
+/// and points are always sent as an object.
+#[derive(Clone, Copy, Debug)]
+pub struct GeoLocation {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl GeoLocation {
+    pub fn new(lat: f64, lon: f64) -> GeoLocation {
+        GeoLocation { lat: lat, lon: lon }
+    }
+
+    /// Convert any serializable point into a `GeoLocation`.
+    ///
+    /// The point is serialized using its own `Serialize` implementation,
+    /// so an `elastic_types` `GeoPoint` can be used regardless of its format.
+    pub fn from_serialize<T>(point: &T) -> Result<GeoLocation, serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde_json::from_value(serde_json::to_value(point)?)
+    }
+
+    /// Decode a geohash into the point at the center of its cell.
+    pub fn from_geohash(hash: &str) -> Option<GeoLocation> {
+        if hash.is_empty() {
+            return None;
+        }
+
+        let (mut lat, mut lon) = ((-90.0, 90.0), (-180.0, 180.0));
+        let mut is_lon = true;
+
+        for c in hash.bytes() {
+            let bits = GEOHASH_ALPHABET
+                .iter()
+                .position(|&a| a == c.to_ascii_lowercase())?;
+
+            for shift in (0..5).rev() {
+                let range: &mut (f64, f64) = if is_lon { &mut lon } else { &mut lat };
+                let mid = (range.0 + range.1) / 2.0;
+
+                if bits & (1 << shift) != 0 {
+                    range.0 = mid;
+                } else {
+                    range.1 = mid;
+                }
+
+                is_lon = !is_lon;
+            }
+        }
+
+        Some(GeoLocation::new(
+            (lat.0 + lat.1) / 2.0,
+            (lon.0 + lon.1) / 2.0,
+        ))
+    }
+
+    fn from_lat_lon_str(s: &str) -> Option<GeoLocation> {
+        let mut parts = s.splitn(2, ',');
+
+        let lat = parts.next()?.trim().parse().ok()?;
+        let lon = parts.next()?.trim().parse().ok()?;
+
+        Some(GeoLocation::new(lat, lon))
+    }
+}
+
+impl PartialEq for GeoLocation {
+    fn eq(&self, other: &GeoLocation) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for GeoLocation {}
+
+impl PartialOrd for GeoLocation {
+    fn partial_cmp(&self, other: &GeoLocation) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GeoLocation {
+    fn cmp(&self, other: &GeoLocation) -> Ordering {
+        cmp_f64(self.lat, other.lat).then(cmp_f64(self.lon, other.lon))
+    }
+}
+
+impl Serialize for GeoLocation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("lat", &self.lat)?;
+        map.serialize_entry("lon", &self.lon)?;
+        map.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for GeoLocation {
+    fn deserialize<D>(deserializer: D) -> Result<GeoLocation, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(GeoLocationVisitor)
+    }
+}
+
+pub(crate) struct GeoLocationVisitor;
+
+impl<'de> Visitor<'de> for GeoLocationVisitor {
+    type Value = GeoLocation;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a geo point as an object, array, 'lat,lon' string or geohash")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let point = if value.contains(',') {
+            GeoLocation::from_lat_lon_str(value)
+        } else {
+            GeoLocation::from_geohash(value)
+        };
+
+        point.ok_or_else(|| E::custom(format!("invalid geo point '{}'", value)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        use serde::de::Error;
+
+        // Arrays are in GeoJSON order, so longitude comes first
+        let lon = seq.next_element()?
+            .ok_or(A::Error::custom("expected a longitude"))?;
+        let lat = seq.next_element()?
+            .ok_or(A::Error::custom("expected a latitude"))?;
+
+        Ok(GeoLocation::new(lat, lon))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let (mut lat, mut lon) = (None, None);
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "lat" => lat = Some(map.next_value()?),
+                "lon" => lon = Some(map.next_value()?),
+                _ => return Err(A::Error::unknown_field(&key, &["lat", "lon"])),
+            }
+        }
+
+        let lat = lat.ok_or(A::Error::missing_field("lat"))?;
+        let lon = lon.ok_or(A::Error::missing_field("lon"))?;
+
+        Ok(GeoLocation::new(lat, lon))
+    }
+}
+
+/// A unit of distance.
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum DistanceUnit {
+    /// For `mi`.
+    Miles,
+    /// For `yd`.
+    Yards,
+    /// For `ft`.
+    Feet,
+    /// For `in`.
+    Inches,
+    /// For `km`.
+    Kilometers,
+    /// For `m`.
+    Meters,
+    /// For `cm`.
+    Centimeters,
+    /// For `mm`.
+    Millimeters,
+    /// For `nmi`.
+    NauticalMiles,
+}
+
+impl DistanceUnit {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            DistanceUnit::Miles => "mi",
+            DistanceUnit::Yards => "yd",
+            DistanceUnit::Feet => "ft",
+            DistanceUnit::Inches => "in",
+            DistanceUnit::Kilometers => "km",
+            DistanceUnit::Meters => "m",
+            DistanceUnit::Centimeters => "cm",
+            DistanceUnit::Millimeters => "mm",
+            DistanceUnit::NauticalMiles => "nmi",
+        }
+    }
+}
+
+impl FromStr for DistanceUnit {
+    type Err = String;
+
+    /// Parse a unit using any of the names Elasticsearch accepts.
+    fn from_str(s: &str) -> Result<DistanceUnit, String> {
+        match s {
+            "mi" | "miles" => Ok(DistanceUnit::Miles),
+            "yd" | "yards" => Ok(DistanceUnit::Yards),
+            "ft" | "feet" => Ok(DistanceUnit::Feet),
+            "in" | "inch" => Ok(DistanceUnit::Inches),
+            "km" | "kilometers" => Ok(DistanceUnit::Kilometers),
+            "m" | "meters" => Ok(DistanceUnit::Meters),
+            "cm" | "centimeters" => Ok(DistanceUnit::Centimeters),
+            "mm" | "millimeters" => Ok(DistanceUnit::Millimeters),
+            "NM" | "nmi" | "nauticalmiles" => Ok(DistanceUnit::NauticalMiles),
+            _ => Err(format!("unknown distance unit '{}'", s)),
+        }
+    }
+}
+
+/// A distance paired with a unit of measure, like `5km`.
+#[derive(Clone, Copy, Debug)]
+pub struct Distance {
+    pub value: f64,
+    pub unit: DistanceUnit,
+}
+
+impl Distance {
+    pub fn new(value: f64, unit: DistanceUnit) -> Distance {
+        Distance {
+            value: value,
+            unit: unit,
+        }
+    }
+}
+
+impl FromStr for Distance {
+    type Err = String;
+
+    /// Parse a distance like `5km` or `12.5 miles`.
+    ///
+    /// A distance without a unit is in meters.
+    fn from_str(s: &str) -> Result<Distance, String> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !(c.is_digit(10) || c == '.' || c == '-' || c == '+'))
+            .unwrap_or(s.len());
+
+        let (value, unit) = s.split_at(split);
+
+        let value = value
+            .parse()
+            .map_err(|_| format!("invalid distance '{}'", s))?;
+
+        let unit = match unit.trim() {
+            "" => DistanceUnit::Meters,
+            unit => unit.parse()?,
+        };
+
+        Ok(Distance::new(value, unit))
+    }
+}
+
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit.as_str())
+    }
+}
+
+impl PartialEq for Distance {
+    fn eq(&self, other: &Distance) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Distance {}
+
+impl PartialOrd for Distance {
+    fn partial_cmp(&self, other: &Distance) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Distance {
+    fn cmp(&self, other: &Distance) -> Ordering {
+        cmp_f64(self.value, other.value).then(self.unit.cmp(&other.unit))
+    }
+}
+
+impl Serialize for Distance {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Distance {
+    fn deserialize<D>(deserializer: D) -> Result<Distance, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DistanceVisitor)
+    }
+}
+
+pub(crate) struct DistanceVisitor;
+
+impl<'de> Visitor<'de> for DistanceVisitor {
+    type Value = Distance;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a distance like '5km' or a number of meters")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Distance::new(value, DistanceUnit::Meters))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_f64(value as f64)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_f64(value as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn geo_location_formats() {
+        let expected = GeoLocation::new(40.0, -70.0);
+
+        let j = r#"{ "lat": 40.0, "lon": -70.0 }"#;
+        assert_eq!(expected, serde_json::from_str(j).unwrap());
+
+        let j = r#"[ -70.0, 40.0 ]"#;
+        assert_eq!(expected, serde_json::from_str(j).unwrap());
+
+        let j = r#""40.0,-70.0""#;
+        assert_eq!(expected, serde_json::from_str(j).unwrap());
+
+        let j = r#"{"lat":40.0,"lon":-70.0}"#;
+        assert_eq!(j, serde_json::to_string(&expected).unwrap());
+    }
+
+    #[test]
+    fn geo_location_geohash() {
+        let point: GeoLocation = serde_json::from_str(r#""ezs42""#).unwrap();
+
+        assert!((point.lat - 42.605).abs() < 0.001);
+        assert!((point.lon - -5.603).abs() < 0.001);
+    }
+
+    #[test]
+    fn geo_location_invalid() {
+        assert!(serde_json::from_str::<GeoLocation>(r#""not a geohash""#).is_err());
+        assert!(serde_json::from_str::<GeoLocation>(r#""40.0,east""#).is_err());
+    }
+
+    #[test]
+    fn geo_location_from_serialize() {
+        #[derive(Serialize)]
+        struct Formatted(&'static str);
+
+        let point = GeoLocation::from_serialize(&Formatted("40.0,-70.0")).unwrap();
+
+        assert_eq!(GeoLocation::new(40.0, -70.0), point);
+    }
+
+    #[test]
+    fn distance_parse() {
+        assert_eq!(
+            Distance::new(5.0, DistanceUnit::Kilometers),
+            "5km".parse().unwrap()
+        );
+        assert_eq!(
+            Distance::new(12.5, DistanceUnit::Miles),
+            "12.5 miles".parse().unwrap()
+        );
+        assert_eq!(
+            Distance::new(1.0, DistanceUnit::NauticalMiles),
+            "1NM".parse().unwrap()
+        );
+        assert_eq!(
+            Distance::new(200.0, DistanceUnit::Meters),
+            "200".parse().unwrap()
+        );
+
+        assert!("5 parsecs".parse::<Distance>().is_err());
+        assert!("km".parse::<Distance>().is_err());
+    }
+
+    #[test]
+    fn distance_serialise() {
+        let d = Distance::new(5.0, DistanceUnit::Kilometers);
+        assert_eq!(r#""5km""#, serde_json::to_string(&d).unwrap());
+
+        let d: Distance = serde_json::from_str("200").unwrap();
+        assert_eq!(Distance::new(200.0, DistanceUnit::Meters), d);
+    }
+}
@@ -0,0 +1,259 @@
+use super::geo::GeoLocation;
+use serde;
+use serde::de::{
+    Deserialize,
+    Visitor,
+};
+use serde::ser::{
+    Serialize,
+    SerializeMap,
+    Serializer,
+};
+use std::fmt;
+
+/// A `geo_bounding_box` query that matches points within a box, like a map viewport.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoBoundingBoxFilter {
+    pub geo_bounding_box: GeoBoundingBoxField,
+}
+
+impl GeoBoundingBoxFilter {
+    pub fn new(field: &str, bounds: GeoBounds) -> GeoBoundingBoxFilter {
+        GeoBoundingBoxFilter {
+            geo_bounding_box: GeoBoundingBoxField {
+                field: field.to_string(),
+                bounds: bounds,
+            },
+        }
+    }
+}
+
+/// The corners of a bounding box.
+///
+/// Elasticsearch accepts a bounding box as `top_left` and `bottom_right` points,
+/// `top_right` and `bottom_left` points, separate `top`, `left`, `bottom` and `right` coordinates
+/// or a `wkt` `BBOX`.
+/// All of these are parsed into the same corners,
+/// and bounding boxes are always sent as `top_left` and `bottom_right` points.
+#[derive(Clone, Debug, Serialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoBounds {
+    pub top_left: GeoLocation,
+    pub bottom_right: GeoLocation,
+}
+
+impl GeoBounds {
+    pub fn new(top_left: GeoLocation, bottom_right: GeoLocation) -> GeoBounds {
+        GeoBounds {
+            top_left: top_left,
+            bottom_right: bottom_right,
+        }
+    }
+
+    fn from_edges(top: f64, left: f64, bottom: f64, right: f64) -> GeoBounds {
+        GeoBounds::new(GeoLocation::new(top, left), GeoLocation::new(bottom, right))
+    }
+
+    /// Parse a `BBOX (left, right, top, bottom)` well-known text envelope.
+    fn from_wkt(wkt: &str) -> Option<GeoBounds> {
+        let wkt = wkt.trim();
+
+        if !wkt.to_uppercase().starts_with("BBOX") {
+            return None;
+        }
+
+        let inner = wkt[4..]
+            .trim()
+            .trim_left_matches('(')
+            .trim_right_matches(')');
+
+        let edges = inner
+            .split(',')
+            .map(|edge| edge.trim().parse().ok())
+            .collect::<Option<Vec<f64>>>()?;
+
+        if edges.len() != 4 {
+            return None;
+        }
+
+        let (left, right, top, bottom) = (edges[0], edges[1], edges[2], edges[3]);
+
+        Some(GeoBounds::from_edges(top, left, bottom, right))
+    }
+}
+
+#[derive(Deserialize)]
+struct GeoBoundsRepr {
+    top_left: Option<GeoLocation>,
+    bottom_right: Option<GeoLocation>,
+    top_right: Option<GeoLocation>,
+    bottom_left: Option<GeoLocation>,
+    top: Option<f64>,
+    left: Option<f64>,
+    bottom: Option<f64>,
+    right: Option<f64>,
+    wkt: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for GeoBounds {
+    fn deserialize<D>(deserializer: D) -> Result<GeoBounds, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let repr = GeoBoundsRepr::deserialize(deserializer)?;
+
+        match repr {
+            GeoBoundsRepr {
+                top_left: Some(top_left),
+                bottom_right: Some(bottom_right),
+                ..
+            } => Ok(GeoBounds::new(top_left, bottom_right)),
+            GeoBoundsRepr {
+                top_right: Some(top_right),
+                bottom_left: Some(bottom_left),
+                ..
+            } => Ok(GeoBounds::from_edges(
+                top_right.lat,
+                bottom_left.lon,
+                bottom_left.lat,
+                top_right.lon,
+            )),
+            GeoBoundsRepr {
+                top: Some(top),
+                left: Some(left),
+                bottom: Some(bottom),
+                right: Some(right),
+                ..
+            } => Ok(GeoBounds::from_edges(top, left, bottom, right)),
+            GeoBoundsRepr { wkt: Some(wkt), .. } => GeoBounds::from_wkt(&wkt)
+                .ok_or_else(|| D::Error::custom(format!("invalid bounding box '{}'", wkt))),
+            _ => Err(D::Error::custom("expected the corners of a bounding box")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoBoundingBoxField {
+    pub field: String,
+    pub bounds: GeoBounds,
+}
+
+impl Serialize for GeoBoundingBoxField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.field, &self.bounds)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GeoBoundingBoxField {
+    fn deserialize<D>(deserializer: D) -> Result<GeoBoundingBoxField, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(GeoBoundingBoxFieldVisitor)
+    }
+}
+
+pub(crate) struct GeoBoundingBoxFieldVisitor;
+
+impl<'de> Visitor<'de> for GeoBoundingBoxFieldVisitor {
+    type Value = GeoBoundingBoxField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a field with a bounding box")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+        let field: String = map.next_key()?.ok_or(A::Error::custom("expected field"))?;
+        let bounds: GeoBounds = map.next_value()?;
+
+        Ok(GeoBoundingBoxField {
+            field: field,
+            bounds: bounds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn expected() -> GeoBoundingBoxFilter {
+        GeoBoundingBoxFilter::new(
+            "pin.location",
+            GeoBounds::new(GeoLocation::new(40.73, -74.1), GeoLocation::new(40.01, -71.12)),
+        )
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_new() {
+        let j = r#"{"geo_bounding_box":{"pin.location":{"top_left":{"lat":40.73,"lon":-74.1},"bottom_right":{"lat":40.01,"lon":-71.12}}}}"#;
+        assert_eq!(j, serde_json::to_string(&expected()).unwrap());
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_corners() {
+        let j = r#"{
+            "geo_bounding_box": {
+                "pin.location": {
+                    "top_left": { "lat": 40.73, "lon": -74.1 },
+                    "bottom_right": [ -71.12, 40.01 ]
+                }
+            }
+        }"#;
+        assert_eq!(expected(), serde_json::from_str(j).unwrap());
+
+        let j = r#"{
+            "geo_bounding_box": {
+                "pin.location": {
+                    "top_right": "40.73,-71.12",
+                    "bottom_left": "40.01,-74.1"
+                }
+            }
+        }"#;
+        assert_eq!(expected(), serde_json::from_str(j).unwrap());
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_edges() {
+        let j = r#"{
+            "geo_bounding_box": {
+                "pin.location": {
+                    "top": 40.73,
+                    "left": -74.1,
+                    "bottom": 40.01,
+                    "right": -71.12
+                }
+            }
+        }"#;
+        assert_eq!(expected(), serde_json::from_str(j).unwrap());
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_wkt() {
+        let j = r#"{
+            "geo_bounding_box": {
+                "pin.location": {
+                    "wkt": "BBOX (-74.1, -71.12, 40.73, 40.01)"
+                }
+            }
+        }"#;
+        assert_eq!(expected(), serde_json::from_str(j).unwrap());
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_invalid() {
+        let j = r#"{ "geo_bounding_box": { "pin.location": { "top": 40.73 } } }"#;
+        assert!(serde_json::from_str::<GeoBoundingBoxFilter>(j).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+use super::common::Float;
+use super::geo::{
+    Distance,
+    GeoLocation,
+};
+use serde;
+use serde::de::Visitor;
+use serde::ser::{
+    Serialize,
+    SerializeMap,
+    Serializer,
+};
+use std::fmt;
+
+/// A `geo_distance` query that matches points within a distance of a central point.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoDistanceFilter {
+    pub geo_distance: GeoDistanceField,
+}
+
+impl GeoDistanceFilter {
+    pub fn new(field: &str, distance: Distance, location: GeoLocation) -> GeoDistanceFilter {
+        GeoDistanceFilter {
+            geo_distance: GeoDistanceField {
+                field: field.to_string(),
+                distance: distance,
+                location: location,
+                distance_type: None,
+                validation_method: None,
+                ignore_unmapped: None,
+                name: None,
+                boost: None,
+            },
+        }
+    }
+
+    /// Set how the distance is calculated.
+    pub fn distance_type(mut self, distance_type: GeoDistanceType) -> Self {
+        self.geo_distance.distance_type = Some(distance_type);
+        self
+    }
+
+    /// Set how invalid points are handled.
+    pub fn validation_method(mut self, validation_method: GeoValidationMethod) -> Self {
+        self.geo_distance.validation_method = Some(validation_method);
+        self
+    }
+
+    /// Match no documents instead of failing when the field isn't mapped.
+    pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
+        self.geo_distance.ignore_unmapped = Some(ignore_unmapped);
+        self
+    }
+
+    /// Set a name for the query that's returned with matching hits.
+    pub fn name(mut self, name: &str) -> Self {
+        self.geo_distance.name = Some(name.to_string());
+        self
+    }
+
+    /// Set a boost for the score of matching documents.
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.geo_distance.boost = Some(Float(boost));
+        self
+    }
+}
+
+/// How the distance between two points is calculated.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum GeoDistanceType {
+    /// The most accurate calculation.
+    Arc,
+    /// A faster calculation that's inaccurate over long distances and near the poles.
+    Plane,
+}
+
+/// How points with invalid coordinates are handled.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GeoValidationMethod {
+    Strict,
+    IgnoreMalformed,
+    Coerce,
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoDistanceField {
+    pub field: String,
+    pub distance: Distance,
+    pub location: GeoLocation,
+    pub distance_type: Option<GeoDistanceType>,
+    pub validation_method: Option<GeoValidationMethod>,
+    pub ignore_unmapped: Option<bool>,
+    pub name: Option<String>,
+    pub boost: Option<Float>,
+}
+
+impl Serialize for GeoDistanceField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("distance", &self.distance)?;
+        map.serialize_entry(&self.field, &self.location)?;
+
+        if let Some(ref distance_type) = self.distance_type {
+            map.serialize_entry("distance_type", distance_type)?;
+        }
+
+        if let Some(ref validation_method) = self.validation_method {
+            map.serialize_entry("validation_method", validation_method)?;
+        }
+
+        if let Some(ref ignore_unmapped) = self.ignore_unmapped {
+            map.serialize_entry("ignore_unmapped", ignore_unmapped)?;
+        }
+
+        if let Some(ref name) = self.name {
+            map.serialize_entry("_name", name)?;
+        }
+
+        if let Some(ref boost) = self.boost {
+            map.serialize_entry("boost", boost)?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for GeoDistanceField {
+    fn deserialize<D>(deserializer: D) -> Result<GeoDistanceField, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(GeoDistanceFieldVisitor)
+    }
+}
+
+pub(crate) struct GeoDistanceFieldVisitor;
+
+impl<'de> Visitor<'de> for GeoDistanceFieldVisitor {
+    type Value = GeoDistanceField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a distance and a field with a geo point")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let (mut distance, mut field) = (None, None);
+        let (mut distance_type, mut validation_method, mut ignore_unmapped, mut name, mut boost) =
+            (None, None, None, None, None);
+
+        // Any key that isn't a known option is the field with the geo point
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "distance" => distance = Some(map.next_value()?),
+                "distance_type" => distance_type = Some(map.next_value()?),
+                "validation_method" => validation_method = Some(map.next_value()?),
+                "ignore_unmapped" => ignore_unmapped = Some(map.next_value()?),
+                "_name" => name = Some(map.next_value()?),
+                "boost" => boost = Some(map.next_value()?),
+                _ => {
+                    if let Some((ref field, _)) = field {
+                        return Err(A::Error::custom(format!(
+                            "expected a single field, but found `{}` and `{}`",
+                            field, key
+                        )));
+                    }
+
+                    let location: GeoLocation = map.next_value()?;
+                    field = Some((key, location));
+                }
+            }
+        }
+
+        let distance = distance.ok_or(A::Error::missing_field("distance"))?;
+        let (field, location) = field.ok_or(A::Error::custom("expected field"))?;
+
+        Ok(GeoDistanceField {
+            field: field,
+            distance: distance,
+            location: location,
+            distance_type: distance_type,
+            validation_method: validation_method,
+            ignore_unmapped: ignore_unmapped,
+            name: name,
+            boost: boost,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::geo::DistanceUnit;
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn geo_distance_filter_new() {
+        let f = GeoDistanceFilter::new(
+            "pin.location",
+            Distance::new(200.0, DistanceUnit::Kilometers),
+            GeoLocation::new(40.0, -70.0),
+        );
+
+        let j = r#"{"geo_distance":{"distance":"200km","pin.location":{"lat":40.0,"lon":-70.0}}}"#;
+        assert_eq!(j, serde_json::to_string(&f).unwrap());
+    }
+
+    #[test]
+    fn geo_distance_filter() {
+        let expected = GeoDistanceFilter::new(
+            "pin.location",
+            Distance::new(12.0, DistanceUnit::Miles),
+            GeoLocation::new(40.0, -70.0),
+        );
+
+        let j = r#"{ "geo_distance": { "distance": "12mi", "pin.location": { "lat": 40, "lon": -70 } } }"#;
+        assert_eq!(expected, serde_json::from_str(j).unwrap());
+
+        let j = r#"{ "geo_distance": { "pin.location": "40,-70", "distance": "12 miles" } }"#;
+        assert_eq!(expected, serde_json::from_str(j).unwrap());
+
+        let j = r#"{ "geo_distance": { "distance": "12mi", "pin.location": [ -70, 40 ] } }"#;
+        assert_eq!(expected, serde_json::from_str(j).unwrap());
+    }
+
+    #[test]
+    fn geo_distance_filter_options() {
+        let location = GeoLocation::new(40.0, -70.0);
+        let expected = GeoDistanceFilter::new("pin.location", Distance::new(12.0, DistanceUnit::Miles), location)
+            .distance_type(GeoDistanceType::Plane)
+            .validation_method(GeoValidationMethod::IgnoreMalformed)
+            .ignore_unmapped(true)
+            .name("near_pin");
+
+        let j = r#"{
+            "geo_distance": {
+                "_name": "near_pin",
+                "distance_type": "plane",
+                "distance": "12mi",
+                "validation_method": "IGNORE_MALFORMED",
+                "ignore_unmapped": true,
+                "pin.location": { "lat": 40, "lon": -70 }
+            }
+        }"#;
+        let f: GeoDistanceFilter = serde_json::from_str(j).unwrap();
+        assert_eq!(expected, f);
+
+        let j = r#"{"geo_distance":{"distance":"12mi","pin.location":{"lat":40.0,"lon":-70.0},"distance_type":"plane","validation_method":"IGNORE_MALFORMED","ignore_unmapped":true,"_name":"near_pin"}}"#;
+        assert_eq!(j, serde_json::to_string(&f).unwrap());
+    }
+
+    #[test]
+    fn geo_distance_filter_multiple_fields() {
+        let j = r#"{ "geo_distance": { "distance": "12mi", "pin.location": "40,-70", "other.location": "40,-70" } }"#;
+        assert!(serde_json::from_str::<GeoDistanceFilter>(j).is_err());
+    }
+}
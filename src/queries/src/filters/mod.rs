@@ -1,14 +1,23 @@
 pub(crate) mod common;
 pub(crate) mod exists;
+pub(crate) mod function_score;
+pub(crate) mod geo;
+pub(crate) mod geo_bounding_box;
+pub(crate) mod geo_distance;
 pub(crate) mod matchfilter;
 pub(crate) mod range;
 pub(crate) mod term;
+pub(crate) mod terms;
 pub(crate) mod wildcard;
 
 pub(crate) use self::exists::ExistsFilter;
+pub(crate) use self::function_score::FunctionScoreFilter;
+pub(crate) use self::geo_bounding_box::GeoBoundingBoxFilter;
+pub(crate) use self::geo_distance::GeoDistanceFilter;
 pub(crate) use self::matchfilter::MatchFilter;
 pub(crate) use self::range::RangeFilter;
 pub(crate) use self::term::TermFilter;
+pub(crate) use self::terms::TermsFilter;
 pub(crate) use self::wildcard::WildcardFilter;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
@@ -16,11 +25,15 @@ pub(crate) use self::wildcard::WildcardFilter;
 #[allow(non_camel_case_types)]
 pub enum Filters {
     term(TermFilter),
+    terms(TermsFilter),
     range(RangeFilter),
     exists(ExistsFilter),
     #[serde(rename = "match")]
     match_(MatchFilter),
     wildcard(WildcardFilter),
+    geo_distance(GeoDistanceFilter),
+    geo_bounding_box(GeoBoundingBoxFilter),
+    function_score(FunctionScoreFilter),
 }
 
 impl From<RangeFilter> for Filters {
@@ -35,6 +48,12 @@ impl From<TermFilter> for Filters {
     }
 }
 
+impl From<TermsFilter> for Filters {
+    fn from(t: TermsFilter) -> Self {
+        Filters::terms(t)
+    }
+}
+
 impl From<ExistsFilter> for Filters {
     fn from(e: ExistsFilter) -> Self {
         Filters::exists(e)
@@ -52,3 +71,21 @@ impl From<WildcardFilter> for Filters {
         Filters::wildcard(w)
     }
 }
+
+impl From<GeoDistanceFilter> for Filters {
+    fn from(g: GeoDistanceFilter) -> Self {
+        Filters::geo_distance(g)
+    }
+}
+
+impl From<GeoBoundingBoxFilter> for Filters {
+    fn from(g: GeoBoundingBoxFilter) -> Self {
+        Filters::geo_bounding_box(g)
+    }
+}
+
+impl From<FunctionScoreFilter> for Filters {
+    fn from(f: FunctionScoreFilter) -> Self {
+        Filters::function_score(f)
+    }
+}
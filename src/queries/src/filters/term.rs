@@ -1,5 +1,7 @@
 use super::super::filters::common::*;
 use super::super::Values;
+use serde::Serialize;
+use serde_json;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct TermFilter {
@@ -12,6 +14,17 @@ impl TermFilter {
             term: FieldAndValue { field: f, value: v },
         }
     }
+
+    /// Create a term filter from a typed value.
+    ///
+    /// The value is serialized using its own `Serialize` implementation,
+    /// so a date is sent in the format of its field instead of needing to be stringified first.
+    pub fn typed<T>(f: &str, v: &T) -> Result<TermFilter, serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(TermFilter::new(f.to_owned(), Values::from_serialize(v)?))
+    }
 }
 
 #[cfg(test)]
@@ -33,4 +46,39 @@ mod tests {
         let j = r#"{ "term":  { "status": "published" }}"#;
         let _s: TermFilter = serde_json::from_str(j).unwrap();
     }
+
+    #[derive(Serialize)]
+    struct Formatted(&'static str);
+
+    #[test]
+    fn term_filter_typed() {
+        let s = TermFilter::typed("user", "Kimchy").unwrap();
+        assert_eq!(r#"{"term":{"user":"Kimchy"}}"#, serde_json::to_string(&s).unwrap());
+
+        let s = TermFilter::typed("user", &1).unwrap();
+        assert_eq!(r#"{"term":{"user":1}}"#, serde_json::to_string(&s).unwrap());
+
+        let s = TermFilter::typed("price", &1.5).unwrap();
+        assert_eq!(r#"{"term":{"price":1.5}}"#, serde_json::to_string(&s).unwrap());
+
+        let s = TermFilter::typed("id", &u64::max_value()).unwrap();
+        assert_eq!(
+            r#"{"term":{"id":18446744073709551615}}"#,
+            serde_json::to_string(&s).unwrap()
+        );
+
+        let s = TermFilter::typed("user", &true).unwrap();
+        assert_eq!(r#"{"term":{"user":true}}"#, serde_json::to_string(&s).unwrap());
+
+        let s = TermFilter::typed("timestamp", &Formatted("20150513")).unwrap();
+        assert_eq!(
+            r#"{"term":{"timestamp":"20150513"}}"#,
+            serde_json::to_string(&s).unwrap()
+        );
+    }
+
+    #[test]
+    fn term_filter_typed_unsupported_value() {
+        assert!(TermFilter::typed("user", &None::<i64>).is_err());
+    }
 }
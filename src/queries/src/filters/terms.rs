@@ -0,0 +1,72 @@
+use super::super::filters::common::*;
+use super::super::Values;
+use serde::Serialize;
+use serde_json;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct TermsFilter {
+    pub terms: FieldAndValues,
+}
+
+impl TermsFilter {
+    pub fn new(f: String, v: Vec<Values>) -> TermsFilter {
+        TermsFilter {
+            terms: FieldAndValues {
+                field: f,
+                values: v,
+            },
+        }
+    }
+
+    /// Create a terms filter from typed values.
+    ///
+    /// Each value is serialized using its own `Serialize` implementation,
+    /// so dates are sent in the format of their field instead of needing to be stringified first.
+    pub fn typed<I, T>(f: &str, v: I) -> Result<TermsFilter, serde_json::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Serialize,
+    {
+        let values = v
+            .into_iter()
+            .map(|v| Values::from_serialize(&v))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TermsFilter::new(f.to_owned(), values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn terms_filter() {
+        let j = r#"{ "terms" : { "user" : ["kimchy", "elasticsearch"] }}"#;
+        let _s: TermsFilter = serde_json::from_str(j).unwrap();
+
+        let j = r#"{ "terms" : { "user" : [1, 2] } }"#;
+        let _s: TermsFilter = serde_json::from_str(j).unwrap();
+    }
+
+    #[test]
+    fn terms_filter_typed() {
+        let s = TermsFilter::typed("user", vec!["kimchy", "elasticsearch"]).unwrap();
+        assert_eq!(
+            r#"{"terms":{"user":["kimchy","elasticsearch"]}}"#,
+            serde_json::to_string(&s).unwrap()
+        );
+
+        let s = TermsFilter::typed("user", vec![1, 2]).unwrap();
+        assert_eq!(r#"{"terms":{"user":[1,2]}}"#, serde_json::to_string(&s).unwrap());
+
+        let s = TermsFilter::typed("price", vec![1.5, 2.0]).unwrap();
+        assert_eq!(r#"{"terms":{"price":[1.5,2.0]}}"#, serde_json::to_string(&s).unwrap());
+    }
+
+    #[test]
+    fn terms_filter_typed_unsupported_value() {
+        assert!(TermsFilter::typed("user", vec![vec![1]]).is_err());
+    }
+}
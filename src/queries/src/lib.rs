@@ -11,6 +11,9 @@ extern crate serde_derive;
 extern crate serde_json;
 //extern crate error_chain;
 
+#[cfg(test)]
+extern crate elastic_types;
+
 mod aggregations;
 mod filters;
 pub mod prelude;
@@ -18,6 +21,7 @@ pub mod prelude;
 use aggregations::Aggregation;
 use aggregations::BucketAggregation;
 use aggregations::EsAggregation;
+use filters::common::Float;
 use filters::Filters;
 use std::collections::HashMap;
 
@@ -26,6 +30,8 @@ use std::collections::HashMap;
 pub enum Values {
     String(String),
     Number(i64),
+    UnsignedNumber(u64),
+    Float(Float),
     Bool(bool),
 }
 
@@ -35,6 +41,44 @@ impl From<i64> for Values {
     }
 }
 
+impl From<f64> for Values {
+    fn from(f: f64) -> Self {
+        Values::Float(Float(f))
+    }
+}
+
+impl From<bool> for Values {
+    fn from(b: bool) -> Self {
+        Values::Bool(b)
+    }
+}
+
+impl From<String> for Values {
+    fn from(s: String) -> Self {
+        Values::String(s)
+    }
+}
+
+impl<'a> From<&'a str> for Values {
+    fn from(s: &'a str) -> Self {
+        Values::String(s.to_owned())
+    }
+}
+
+impl Values {
+    /// Convert any serializable value into a `Values`.
+    ///
+    /// The value is serialized using its own `Serialize` implementation,
+    /// so a typed value like an `elastic_types` `Date` keeps the format of its field.
+    /// Values that don't serialize as a string, number or boolean are an error.
+    pub fn from_serialize<T>(value: &T) -> Result<Values, serde_json::Error>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        serde_json::from_value(serde_json::to_value(value)?)
+    }
+}
+
 pub enum BoolQuerySections {
     Must,
     Should,
@@ -42,7 +86,7 @@ pub enum BoolQuerySections {
     MustNot,
 }
 
-#[derive(Builder, Clone, Debug, Serialize, Deserialize)]
+#[derive(Builder, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Bool {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
@@ -146,7 +190,7 @@ impl Bool {
     }
 }
 
-#[derive(Builder, Clone, Debug, Serialize, Deserialize)]
+#[derive(Builder, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct QueryField {
     #[builder(default = "self.default_bool()?")]
     pub bool: Bool,
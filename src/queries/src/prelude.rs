@@ -1,8 +1,42 @@
-pub use filters::common::EsDateFormat;
+pub use filters::common::{
+    EsDateFormat,
+    Float,
+};
+pub use filters::function_score::{
+    BoostMode,
+    DecayFunction,
+    DecayKind,
+    FieldValueFactor,
+    FieldValueFactorModifier,
+    FunctionScore,
+    FunctionScoreFilter,
+    MultiValueMode,
+    ScoreFunction,
+    ScoreMode,
+    ScriptScore,
+};
+pub use filters::geo::{
+    Distance,
+    DistanceUnit,
+    GeoLocation,
+};
+pub use filters::geo_bounding_box::{
+    GeoBoundingBoxFilter,
+    GeoBounds,
+};
+pub use filters::geo_distance::{
+    GeoDistanceFilter,
+    GeoDistanceType,
+    GeoValidationMethod,
+};
 pub use filters::range::{
     RangeFilter,
     RangeParamsBuilder,
 };
+pub use filters::term::TermFilter;
+pub use filters::terms::TermsFilter;
+pub use filters::Filters;
+pub use Values;
 pub use BoolQuerySections;
 pub use Query;
 pub use QueryBuilder;
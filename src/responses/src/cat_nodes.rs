@@ -0,0 +1,125 @@
+/*!
+Response types for a [cat nodes request](https://www.elastic.co/guide/en/elasticsearch/reference/master/cat-nodes.html).
+*/
+
+use std::slice::Iter;
+
+use common::deserialize_cat_value;
+use parsing::IsOkOnSuccess;
+
+/**
+Response for a [cat nodes request](https://www.elastic.co/guide/en/elasticsearch/reference/master/cat-nodes.html).
+
+The request must be sent with `format=json`.
+Columns that aren't returned by Elasticsearch are `None`.
+*/
+#[derive(Deserialize, Debug)]
+pub struct CatNodesResponse(Vec<CatNode>);
+
+impl CatNodesResponse {
+    /** Iterate through the nodes in the cluster. */
+    pub fn iter(&self) -> Iter<CatNode> {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for CatNodesResponse {
+    type Item = CatNode;
+    type IntoIter = ::std::vec::IntoIter<CatNode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/** A single row in a cat nodes response. */
+#[derive(Deserialize, Debug)]
+pub struct CatNode {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    ip: Option<String>,
+    #[serde(rename = "heap.percent", default, deserialize_with = "deserialize_cat_value")]
+    heap_percent: Option<u32>,
+    #[serde(rename = "ram.percent", default, deserialize_with = "deserialize_cat_value")]
+    ram_percent: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_cat_value")]
+    cpu: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_cat_value")]
+    load_1m: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_cat_value")]
+    load_5m: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_cat_value")]
+    load_15m: Option<f64>,
+    #[serde(rename = "node.role", default)]
+    node_role: Option<String>,
+    #[serde(default)]
+    master: Option<String>,
+}
+
+impl CatNode {
+    /** The name of the node. */
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(|name| name.as_str())
+    }
+
+    /** The ip address of the node. */
+    pub fn ip(&self) -> Option<&str> {
+        self.ip.as_ref().map(|ip| ip.as_str())
+    }
+
+    /** The percentage of the heap in use. */
+    pub fn heap_percent(&self) -> Option<u32> {
+        self.heap_percent
+    }
+
+    /** The percentage of the total memory in use. */
+    pub fn ram_percent(&self) -> Option<u32> {
+        self.ram_percent
+    }
+
+    /** The recent cpu usage as a percentage. */
+    pub fn cpu(&self) -> Option<u32> {
+        self.cpu
+    }
+
+    /** The load average over the last minute. */
+    pub fn load_1m(&self) -> Option<f64> {
+        self.load_1m
+    }
+
+    /** The load average over the last five minutes. */
+    pub fn load_5m(&self) -> Option<f64> {
+        self.load_5m
+    }
+
+    /** The load average over the last fifteen minutes. */
+    pub fn load_15m(&self) -> Option<f64> {
+        self.load_15m
+    }
+
+    /**
+    The roles of the node.
+
+    Each role is a single character, such as `m` for master-eligible, `d` for data and `i` for ingest.
+    A coordinating only node has the role `-`.
+    */
+    pub fn node_role(&self) -> Option<&str> {
+        self.node_role.as_ref().map(|node_role| node_role.as_str())
+    }
+
+    /** Whether or not the node has the given single character role, such as `d` for data. */
+    pub fn has_role(&self, role: char) -> bool {
+        self.node_role
+            .as_ref()
+            .map(|node_role| node_role.contains(role))
+            .unwrap_or(false)
+    }
+
+    /** Whether or not the node is the elected master. */
+    pub fn is_master(&self) -> bool {
+        self.master.as_ref().map(|master| master == "*").unwrap_or(false)
+    }
+}
+
+impl IsOkOnSuccess for CatNodesResponse {}
@@ -0,0 +1,149 @@
+/*!
+Response types for a [cat shards request](https://www.elastic.co/guide/en/elasticsearch/reference/master/cat-shards.html).
+*/
+
+use serde::de::{
+    Deserialize,
+    Deserializer,
+};
+use std::slice::Iter;
+
+use common::deserialize_cat_value;
+use parsing::IsOkOnSuccess;
+
+/**
+Response for a [cat shards request](https://www.elastic.co/guide/en/elasticsearch/reference/master/cat-shards.html).
+
+The request must be sent with `format=json`.
+Sizes are only parsed when the request is also sent with `bytes=b`.
+Columns that aren't returned by Elasticsearch are `None`.
+*/
+#[derive(Deserialize, Debug)]
+pub struct CatShardsResponse(Vec<CatShard>);
+
+impl CatShardsResponse {
+    /** Iterate through the shards in the cluster. */
+    pub fn iter(&self) -> Iter<CatShard> {
+        self.0.iter()
+    }
+
+    /** Iterate through the shards that aren't assigned to a node. */
+    pub fn unassigned(&self) -> impl Iterator<Item = &CatShard> {
+        self.0
+            .iter()
+            .filter(|shard| shard.state() == &ShardState::Unassigned)
+    }
+}
+
+impl IntoIterator for CatShardsResponse {
+    type Item = CatShard;
+    type IntoIter = ::std::vec::IntoIter<CatShard>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/** A single row in a cat shards response. */
+#[derive(Deserialize, Debug)]
+pub struct CatShard {
+    index: String,
+    #[serde(deserialize_with = "deserialize_cat_value")]
+    shard: Option<u32>,
+    prirep: String,
+    state: ShardState,
+    #[serde(default, deserialize_with = "deserialize_cat_value")]
+    docs: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_cat_value")]
+    store: Option<u64>,
+    #[serde(default)]
+    ip: Option<String>,
+    #[serde(default)]
+    node: Option<String>,
+}
+
+impl CatShard {
+    /** The name of the index the shard belongs to. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The number of the shard within its index. */
+    pub fn shard(&self) -> Option<u32> {
+        self.shard
+    }
+
+    /** Whether or not the shard is a primary. */
+    pub fn is_primary(&self) -> bool {
+        self.prirep == "p"
+    }
+
+    /** The state of the shard. */
+    pub fn state(&self) -> &ShardState {
+        &self.state
+    }
+
+    /** The number of documents in the shard. */
+    pub fn docs(&self) -> Option<u64> {
+        self.docs
+    }
+
+    /** The size of the shard on disk in bytes. */
+    pub fn store(&self) -> Option<u64> {
+        self.store
+    }
+
+    /** The ip address of the node the shard is allocated to. */
+    pub fn ip(&self) -> Option<&str> {
+        self.ip.as_ref().map(|ip| ip.as_str())
+    }
+
+    /**
+    The node the shard is allocated to.
+
+    This may include details of a relocation, like `node-1 -> 127.0.0.1 abcd node-2`.
+    */
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_ref().map(|node| node.as_str())
+    }
+}
+
+/**
+The state of a shard.
+
+States that aren't recognised are returned as `Other`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShardState {
+    /** The shard is allocated and active. */
+    Started,
+    /** The shard is moving to another node. */
+    Relocating,
+    /** The shard is being recovered. */
+    Initializing,
+    /** The shard isn't allocated to any node. */
+    Unassigned,
+    /** Some other state. */
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for ShardState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let state = String::deserialize(deserializer)?;
+
+        let state = match state.as_str() {
+            "STARTED" => ShardState::Started,
+            "RELOCATING" => ShardState::Relocating,
+            "INITIALIZING" => ShardState::Initializing,
+            "UNASSIGNED" => ShardState::Unassigned,
+            _ => ShardState::Other(state),
+        };
+
+        Ok(state)
+    }
+}
+
+impl IsOkOnSuccess for CatShardsResponse {}
@@ -0,0 +1,57 @@
+/*!
+Response types for a [cluster settings request](https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-update-settings.html).
+*/
+
+use serde_json::{
+    Map,
+    Value,
+};
+
+use parsing::IsOkOnSuccess;
+
+/**
+Response for getting or updating [cluster settings](https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-update-settings.html).
+
+Settings are keyed by their flat name, like `cluster.routing.allocation.enable`.
+*/
+#[derive(Deserialize, Debug)]
+pub struct ClusterSettingsResponse {
+    #[serde(default)]
+    acknowledged: Option<bool>,
+    #[serde(default)]
+    persistent: Map<String, Value>,
+    #[serde(default)]
+    transient: Map<String, Value>,
+}
+
+impl ClusterSettingsResponse {
+    /**
+    Whether or not an update was acknowledged.
+
+    This is only returned when settings are updated.
+    */
+    pub fn acknowledged(&self) -> Option<bool> {
+        self.acknowledged
+    }
+
+    /** Settings that survive a full cluster restart. */
+    pub fn persistent(&self) -> &Map<String, Value> {
+        &self.persistent
+    }
+
+    /** Settings that are cleared on a full cluster restart. */
+    pub fn transient(&self) -> &Map<String, Value> {
+        &self.transient
+    }
+
+    /**
+    Get the value of an explicitly set setting.
+
+    Transient settings take precedence over persistent ones, which matches how Elasticsearch applies them.
+    */
+    pub fn setting(&self, key: &str) -> Option<&Value> {
+        self.transient.get(key).or_else(|| self.persistent.get(key))
+    }
+}
+
+impl IsOkOnSuccess for ClusterSettingsResponse {}
@@ -1,3 +1,12 @@
+use serde::de::{
+    Deserialize,
+    Deserializer,
+    Error,
+};
+use serde_json::Value;
+use std::fmt::Display;
+use std::str::FromStr;
+
 /** A default type for allocated fields in responses. */
 pub(crate) type DefaultAllocatedField = String;
 
@@ -26,6 +35,72 @@ impl Shards {
     }
 }
 
+/** Returned hits metadata along with the reasons any shards failed. */
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct ShardsWithFailures {
+    #[serde(flatten)]
+    shards: Shards,
+    #[serde(default)]
+    failures: Vec<ShardFailure>,
+}
+
+impl ShardsWithFailures {
+    pub(crate) fn shards(&self) -> &Shards {
+        &self.shards
+    }
+
+    pub(crate) fn failures(&self) -> &[ShardFailure] {
+        &self.failures
+    }
+}
+
+/** A shard that failed to process a request. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShardFailure {
+    #[serde(default)]
+    index: Option<String>,
+    #[serde(default)]
+    shard: Option<u32>,
+    #[serde(default)]
+    node: Option<String>,
+    reason: ShardFailureReason,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct ShardFailureReason {
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+impl ShardFailure {
+    /** The index the failed shard belongs to. */
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_ref().map(|index| index.as_str())
+    }
+
+    /** The number of the failed shard within its index. */
+    pub fn shard(&self) -> Option<u32> {
+        self.shard
+    }
+
+    /** The id of the node the failed shard is allocated to. */
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_ref().map(|node| node.as_str())
+    }
+
+    /** The type of error that caused the shard to fail, like `query_shard_exception`. */
+    pub fn ty(&self) -> &str {
+        &self.reason.ty
+    }
+
+    /** A description of why the shard failed. */
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.reason.as_ref().map(|reason| reason.as_str())
+    }
+}
+
 #[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
 pub(crate) enum DocumentResult {
     #[serde(rename = "deleted")]
@@ -39,3 +114,33 @@ pub(crate) enum DocumentResult {
     #[serde(rename = "created")]
     Created,
 }
+
+/**
+Deserialise a column value from a `_cat` API.
+
+The `_cat` APIs return numeric columns as strings, and missing columns as `null` or an empty string.
+*/
+pub(crate) fn deserialize_cat_value<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::String(value)) => value,
+        Some(Value::Number(value)) => value.to_string(),
+        Some(Value::Null) | None => return Ok(None),
+        Some(value) => {
+            return Err(D::Error::custom(format_args!(
+                "expected a string or number, but got `{}`",
+                value
+            )))
+        }
+    };
+
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    value.parse().map(Some).map_err(D::Error::custom)
+}
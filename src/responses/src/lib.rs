@@ -141,14 +141,21 @@ pub mod error;
 pub mod parsing;
 
 pub mod bulk;
+mod cat_nodes;
+mod cat_shards;
+mod cluster_settings;
 mod command;
 mod common;
 mod delete;
 mod get;
 mod index;
+mod nodes_hot_threads;
 mod ping;
 pub mod search;
+mod snapshot_status;
+mod tasks_list;
 mod update;
+mod validate_query;
 
 mod indices_exists;
 
@@ -156,14 +163,21 @@ pub use self::bulk::{
     BulkErrorsResponse,
     BulkResponse,
 };
+pub use self::cat_nodes::*;
+pub use self::cat_shards::*;
+pub use self::cluster_settings::*;
 pub use self::command::*;
 pub use self::common::*;
 pub use self::delete::*;
 pub use self::get::*;
 pub use self::index::*;
+pub use self::nodes_hot_threads::*;
 pub use self::ping::*;
 pub use self::search::SearchResponse;
+pub use self::snapshot_status::*;
+pub use self::tasks_list::*;
 pub use self::update::*;
+pub use self::validate_query::*;
 
 pub use self::indices_exists::*;
 
@@ -0,0 +1,32 @@
+/*!
+Response types for a [nodes hot threads request](https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-nodes-hot-threads.html).
+*/
+
+/**
+Response for a [nodes hot threads request](https://www.elastic.co/guide/en/elasticsearch/reference/master/cluster-nodes-hot-threads.html).
+
+Hot threads are returned as plain text rather than JSON, so this response isn't deserialised using `parse`.
+It's a wrapper around the text of the response body.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodesHotThreadsResponse {
+    text: String,
+}
+
+impl NodesHotThreadsResponse {
+    /** The plain text hot threads report. */
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /** Convert the response into the plain text hot threads report. */
+    pub fn into_text(self) -> String {
+        self.text
+    }
+}
+
+impl From<String> for NodesHotThreadsResponse {
+    fn from(text: String) -> Self {
+        NodesHotThreadsResponse { text: text }
+    }
+}
@@ -8,7 +8,11 @@ use serde_json::{
     Value,
 };
 
-use common::Shards;
+use common::{
+    ShardFailure,
+    Shards,
+    ShardsWithFailures,
+};
 use parsing::IsOkOnSuccess;
 
 use std::borrow::Cow;
@@ -59,7 +63,7 @@ pub struct SearchResponse<T> {
     took: u64,
     timed_out: bool,
     #[serde(rename = "_shards")]
-    shards: Shards,
+    shards: ShardsWithFailures,
     hits: HitsWrapper<T>,
     aggregations: Option<AggsWrapper>,
     status: Option<u16>,
@@ -80,14 +84,27 @@ impl<T> SearchResponse<T> {
         self.took
     }
 
-    /** Whether or not the request timed out before completing. */
+    /**
+    Whether or not the request timed out before completing.
+
+    If the search hit a server-side `timeout` then the hits and aggregations may only be partial results.
+    */
     pub fn timed_out(&self) -> bool {
         self.timed_out
     }
 
     /** Shards metadata for the request. */
     pub fn shards(&self) -> &Shards {
-        &self.shards
+        self.shards.shards()
+    }
+
+    /**
+    The reasons shards failed to process the request.
+
+    A search can still succeed when some shards fail, so the hits and aggregations may only be partial results if this isn't empty.
+    */
+    pub fn shard_failures(&self) -> &[ShardFailure] {
+        self.shards.failures()
     }
 
     /** A http status associated with the response. */
@@ -0,0 +1,199 @@
+/*!
+Response types for a [snapshot status request](https://www.elastic.co/guide/en/elasticsearch/reference/master/modules-snapshots.html#_snapshot_status).
+*/
+
+use serde::de::{
+    Deserialize,
+    Deserializer,
+};
+use std::collections::hash_map::Iter as HashMapIter;
+use std::collections::HashMap;
+use std::slice::Iter;
+
+use parsing::IsOkOnSuccess;
+
+/** Response for a [snapshot status request](https://www.elastic.co/guide/en/elasticsearch/reference/master/modules-snapshots.html#_snapshot_status). */
+#[derive(Deserialize, Debug)]
+pub struct SnapshotStatusResponse {
+    snapshots: Vec<SnapshotStatus>,
+}
+
+impl SnapshotStatusResponse {
+    /** Iterate through the status of each snapshot. */
+    pub fn iter(&self) -> Iter<SnapshotStatus> {
+        self.snapshots.iter()
+    }
+}
+
+impl IntoIterator for SnapshotStatusResponse {
+    type Item = SnapshotStatus;
+    type IntoIter = ::std::vec::IntoIter<SnapshotStatus>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.snapshots.into_iter()
+    }
+}
+
+/** The status of a single snapshot. */
+#[derive(Deserialize, Debug)]
+pub struct SnapshotStatus {
+    snapshot: String,
+    repository: String,
+    uuid: Option<String>,
+    state: SnapshotState,
+    shards_stats: ShardsStats,
+    #[serde(default)]
+    indices: HashMap<String, IndexStatus>,
+}
+
+impl SnapshotStatus {
+    /** The name of the snapshot. */
+    pub fn snapshot(&self) -> &str {
+        &self.snapshot
+    }
+
+    /** The name of the repository the snapshot belongs to. */
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /** The unique id of the snapshot. */
+    pub fn uuid(&self) -> Option<&str> {
+        self.uuid.as_ref().map(|uuid| uuid.as_str())
+    }
+
+    /** The state of the snapshot. */
+    pub fn state(&self) -> &SnapshotState {
+        &self.state
+    }
+
+    /** Shard stats for the snapshot across all indices. */
+    pub fn shards_stats(&self) -> ShardsStats {
+        self.shards_stats
+    }
+
+    /**
+    Whether or not any shards in the snapshot failed.
+
+    A partial snapshot is reported as a `Success`, so use this to find snapshots that are missing some data.
+    */
+    pub fn has_failed_shards(&self) -> bool {
+        self.shards_stats.failed > 0
+    }
+
+    /** Iterate through the status of each index in the snapshot. */
+    pub fn indices(&self) -> IndexStatusIter {
+        IndexStatusIter(self.indices.iter())
+    }
+}
+
+/** A borrowing iterator over the indices in a snapshot. */
+pub struct IndexStatusIter<'a>(HashMapIter<'a, String, IndexStatus>);
+
+impl<'a> Iterator for IndexStatusIter<'a> {
+    type Item = (&'a str, &'a IndexStatus);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(index, status)| (index.as_str(), status))
+    }
+}
+
+/** The status of a single index in a snapshot. */
+#[derive(Deserialize, Debug)]
+pub struct IndexStatus {
+    shards_stats: ShardsStats,
+}
+
+impl IndexStatus {
+    /** Shard stats for the index. */
+    pub fn shards_stats(&self) -> ShardsStats {
+        self.shards_stats
+    }
+}
+
+/** The number of shards in each stage of a snapshot. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ShardsStats {
+    initializing: u32,
+    started: u32,
+    finalizing: u32,
+    done: u32,
+    failed: u32,
+    total: u32,
+}
+
+impl ShardsStats {
+    /** The number of shards that are initialising. */
+    pub fn initializing(&self) -> u32 {
+        self.initializing
+    }
+
+    /** The number of shards that have started. */
+    pub fn started(&self) -> u32 {
+        self.started
+    }
+
+    /** The number of shards that are finalising. */
+    pub fn finalizing(&self) -> u32 {
+        self.finalizing
+    }
+
+    /** The number of shards that are done. */
+    pub fn done(&self) -> u32 {
+        self.done
+    }
+
+    /** The number of shards that failed. */
+    pub fn failed(&self) -> u32 {
+        self.failed
+    }
+
+    /** The total number of shards. */
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+/**
+The state of a snapshot.
+
+A snapshot that finished with some failed shards is still a `Success`, so check `SnapshotStatus::has_failed_shards` too.
+States that aren't recognised are returned as `Other`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotState {
+    /** The snapshot is being initialised. */
+    Init,
+    /** The snapshot is currently running. */
+    Started,
+    /** The snapshot finished and its shards were stored. */
+    Success,
+    /** The snapshot finished with an error and no data was stored. */
+    Failed,
+    /** The snapshot was aborted before it finished. */
+    Aborted,
+    /** Some other state. */
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for SnapshotState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let state = String::deserialize(deserializer)?;
+
+        let state = match state.as_str() {
+            "INIT" => SnapshotState::Init,
+            "STARTED" => SnapshotState::Started,
+            "SUCCESS" => SnapshotState::Success,
+            "FAILED" => SnapshotState::Failed,
+            "ABORTED" => SnapshotState::Aborted,
+            _ => SnapshotState::Other(state),
+        };
+
+        Ok(state)
+    }
+}
+
+impl IsOkOnSuccess for SnapshotStatusResponse {}
@@ -0,0 +1,147 @@
+/*!
+Response types for a [task listing request](https://www.elastic.co/guide/en/elasticsearch/reference/master/tasks.html).
+*/
+
+use serde_json::Value;
+use std::collections::hash_map::Values;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parsing::IsOkOnSuccess;
+
+/** Response for a [task listing request](https://www.elastic.co/guide/en/elasticsearch/reference/master/tasks.html). */
+#[derive(Deserialize, Debug)]
+pub struct TasksListResponse {
+    #[serde(default)]
+    nodes: HashMap<String, TaskNode>,
+    #[serde(default)]
+    node_failures: Vec<Value>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TaskNode {
+    #[serde(default)]
+    tasks: HashMap<String, Task>,
+}
+
+/** A single task running on a node. */
+#[derive(Deserialize, Debug)]
+pub struct Task {
+    node: String,
+    id: u64,
+    action: String,
+    #[serde(rename = "type")]
+    ty: String,
+    description: Option<String>,
+    start_time_in_millis: u64,
+    running_time_in_nanos: u64,
+    cancellable: bool,
+    parent_task_id: Option<String>,
+}
+
+impl TasksListResponse {
+    /**
+    Iterate through the tasks running on all nodes.
+
+    Tasks are not returned in any particular order.
+    */
+    pub fn tasks(&self) -> TaskIter {
+        TaskIter {
+            nodes: self.nodes.values(),
+            tasks: None,
+        }
+    }
+
+    /** Any failures returned by nodes that couldn't list their tasks. */
+    pub fn node_failures(&self) -> &[Value] {
+        &self.node_failures
+    }
+}
+
+/** A borrowing iterator over the tasks in a `TasksListResponse`. */
+pub struct TaskIter<'a> {
+    nodes: Values<'a, String, TaskNode>,
+    tasks: Option<Values<'a, String, Task>>,
+}
+
+impl<'a> Iterator for TaskIter<'a> {
+    type Item = &'a Task;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(task) = self.tasks.as_mut().and_then(|tasks| tasks.next()) {
+                return Some(task);
+            }
+
+            match self.nodes.next() {
+                Some(node) => self.tasks = Some(node.tasks.values()),
+                None => return None,
+            }
+        }
+    }
+}
+
+impl Task {
+    /**
+    The full id of the task.
+
+    This is the node id and task number in the form `{node}:{id}`.
+    */
+    pub fn task_id(&self) -> String {
+        format!("{}:{}", self.node, self.id)
+    }
+
+    /** The id of the node the task is running on. */
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    /** The task number, unique to the node it's running on. */
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /** The action the task is performing, such as `indices:data/write/bulk`. */
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /** The kind of task, such as `transport` or `direct`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /**
+    A description of the task.
+
+    This is only returned when tasks are listed with `detailed`.
+    */
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(|description| description.as_str())
+    }
+
+    /** The time the task started in milliseconds since the epoch. */
+    pub fn start_time_in_millis(&self) -> u64 {
+        self.start_time_in_millis
+    }
+
+    /** How long the task has been running for. */
+    pub fn running_time(&self) -> Duration {
+        Duration::new(
+            self.running_time_in_nanos / 1_000_000_000,
+            (self.running_time_in_nanos % 1_000_000_000) as u32,
+        )
+    }
+
+    /** Whether or not the task can be cancelled. */
+    pub fn cancellable(&self) -> bool {
+        self.cancellable
+    }
+
+    /** The full id of the task that spawned this one, if any. */
+    pub fn parent_task_id(&self) -> Option<&str> {
+        self.parent_task_id.as_ref().map(|id| id.as_str())
+    }
+}
+
+impl IsOkOnSuccess for TasksListResponse {}
@@ -0,0 +1,104 @@
+/*!
+Response types for a [validate query request](https://www.elastic.co/guide/en/elasticsearch/reference/master/search-validate.html).
+*/
+
+use std::slice::Iter;
+
+use common::Shards;
+use parsing::IsOkOnSuccess;
+
+/** Response for a [validate query request](https://www.elastic.co/guide/en/elasticsearch/reference/master/search-validate.html). */
+#[derive(Deserialize, Debug)]
+pub struct ValidateQueryResponse {
+    valid: bool,
+    #[serde(rename = "_shards")]
+    shards: Option<Shards>,
+    #[serde(default)]
+    explanations: Vec<QueryExplanation>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+impl ValidateQueryResponse {
+    /** Whether or not the query is valid. */
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> Option<&Shards> {
+        self.shards.as_ref()
+    }
+
+    /**
+    Iterate through the explanations for the query.
+
+    Explanations are only returned when the request is sent with `explain` or `rewrite`.
+    */
+    pub fn explanations(&self) -> Iter<QueryExplanation> {
+        self.explanations.iter()
+    }
+
+    /**
+    The reason the query couldn't be parsed.
+
+    This is only returned when the request is sent with `explain`.
+    */
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_ref().map(|error| error.as_str())
+    }
+
+    /** Iterate through the errors for the query and any index or shard it isn't valid for. */
+    pub fn errors(&self) -> impl Iterator<Item = &str> {
+        self.error().into_iter().chain(
+            self.explanations
+                .iter()
+                .filter_map(|explanation| explanation.error()),
+        )
+    }
+}
+
+/** An explanation of the query for a single index or shard. */
+#[derive(Deserialize, Debug)]
+pub struct QueryExplanation {
+    index: Option<String>,
+    shard: Option<u32>,
+    valid: bool,
+    error: Option<String>,
+    explanation: Option<String>,
+}
+
+impl QueryExplanation {
+    /** The index the query was validated against. */
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_ref().map(|index| index.as_str())
+    }
+
+    /**
+    The shard the query was validated against.
+
+    This is only returned when the request is sent with `all_shards`.
+    */
+    pub fn shard(&self) -> Option<u32> {
+        self.shard
+    }
+
+    /** Whether or not the query is valid for this index or shard. */
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /** The reason the query isn't valid. */
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_ref().map(|error| error.as_str())
+    }
+
+    /** The query as it will be executed, after being parsed and rewritten. */
+    pub fn explanation(&self) -> Option<&str> {
+        self.explanation
+            .as_ref()
+            .map(|explanation| explanation.as_str())
+    }
+}
+
+impl IsOkOnSuccess for ValidateQueryResponse {}
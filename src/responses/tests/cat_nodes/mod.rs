@@ -0,0 +1,57 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_cat_nodes_response() {
+    let f = load_file("tests/samples/cat_nodes.json");
+    let deserialized = parse::<CatNodesResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    let nodes: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!(2, nodes.len());
+
+    let node = nodes[0];
+
+    assert_eq!(Some("mJw06l1"), node.name());
+    assert_eq!(Some("127.0.0.1"), node.ip());
+    assert_eq!(Some(65), node.heap_percent());
+    assert_eq!(Some(99), node.ram_percent());
+    assert_eq!(Some(42), node.cpu());
+    assert_eq!(Some(3.07), node.load_1m());
+    assert_eq!(None, node.load_5m());
+    assert_eq!(Some("mdi"), node.node_role());
+    assert!(node.has_role('m'));
+    assert!(node.is_master());
+
+    let node = nodes[1];
+
+    assert!(!node.has_role('m'));
+    assert!(!node.is_master());
+    assert_eq!(Some(0.31), node.load_15m());
+}
+
+#[test]
+fn success_parse_cat_nodes_response_missing_columns() {
+    let body = json!([
+        {
+            "name": "mJw06l1",
+            "cpu": ""
+        }
+    ]);
+
+    let deserialized = parse::<CatNodesResponse>()
+        .from_slice(StatusCode::OK, serde_json::to_vec(&body).unwrap())
+        .unwrap();
+
+    let node = deserialized.iter().next().unwrap();
+
+    assert_eq!(Some("mJw06l1"), node.name());
+    assert_eq!(None, node.cpu());
+    assert_eq!(None, node.heap_percent());
+    assert_eq!(None, node.node_role());
+}
@@ -0,0 +1,65 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_cat_shards_response() {
+    let f = load_file("tests/samples/cat_shards.json");
+    let deserialized = parse::<CatShardsResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    let shards: Vec<_> = deserialized.iter().collect();
+
+    assert_eq!(2, shards.len());
+
+    let shard = shards[0];
+
+    assert_eq!("twitter", shard.index());
+    assert_eq!(Some(0), shard.shard());
+    assert!(shard.is_primary());
+    assert_eq!(&ShardState::Started, shard.state());
+    assert_eq!(Some(3014), shard.docs());
+    assert_eq!(Some(31416), shard.store());
+    assert_eq!(Some("mJw06l1"), shard.node());
+
+    let shard = shards[1];
+
+    assert!(!shard.is_primary());
+    assert_eq!(&ShardState::Unassigned, shard.state());
+    assert_eq!(None, shard.docs());
+    assert_eq!(None, shard.node());
+}
+
+#[test]
+fn success_parse_unassigned_shards() {
+    let f = load_file("tests/samples/cat_shards.json");
+    let deserialized = parse::<CatShardsResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    let unassigned: Vec<_> = deserialized.unassigned().collect();
+
+    assert_eq!(1, unassigned.len());
+    assert!(!unassigned[0].is_primary());
+}
+
+#[test]
+fn error_parse_unparseable_cat_value() {
+    let body = json!([
+        {
+            "index": "twitter",
+            "shard": "0",
+            "prirep": "p",
+            "state": "STARTED",
+            "store": "30.6kb"
+        }
+    ]);
+
+    let deserialized = parse::<CatShardsResponse>()
+        .from_slice(StatusCode::OK, serde_json::to_vec(&body).unwrap());
+
+    assert!(deserialized.is_err());
+}
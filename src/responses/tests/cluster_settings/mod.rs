@@ -0,0 +1,40 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_cluster_settings_get() {
+    let f = load_file("tests/samples/cluster_settings_get.json");
+    let deserialized = parse::<ClusterSettingsResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    assert_eq!(None, deserialized.acknowledged());
+    assert_eq!(2, deserialized.persistent().len());
+    assert_eq!(
+        Some(&json!("primaries")),
+        deserialized.setting("cluster.routing.allocation.enable")
+    );
+    assert_eq!(
+        Some(&json!("50mb")),
+        deserialized.setting("indices.recovery.max_bytes_per_sec")
+    );
+    assert_eq!(None, deserialized.setting("cluster.routing.rebalance.enable"));
+}
+
+#[test]
+fn success_parse_cluster_settings_put() {
+    let f = load_file("tests/samples/cluster_settings_put.json");
+    let deserialized = parse::<ClusterSettingsResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    assert_eq!(Some(true), deserialized.acknowledged());
+    assert!(deserialized.persistent().is_empty());
+    assert_eq!(
+        Some(&json!("none")),
+        deserialized.transient().get("cluster.routing.allocation.enable")
+    );
+}
@@ -11,9 +11,15 @@ fn load_file(p: &str) -> File {
 }
 
 pub mod bulk;
+pub mod cat_nodes;
+pub mod cat_shards;
+pub mod cluster_settings;
 pub mod command;
 pub mod get;
 pub mod index;
 pub mod indices_exists;
 pub mod ping;
 pub mod search;
+pub mod snapshot_status;
+pub mod tasks_list;
+pub mod validate_query;
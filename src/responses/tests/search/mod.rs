@@ -17,6 +17,35 @@ fn success_parse_empty() {
     assert_eq!(deserialized.hits().into_iter().count(), 0);
 }
 
+#[test]
+fn success_parse_empty_has_no_shard_failures() {
+    let f = load_file("tests/samples/search_empty.json");
+    let deserialized = parse::<SearchResponse<Value>>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    assert!(deserialized.shard_failures().is_empty());
+}
+
+#[test]
+fn success_parse_shard_failures() {
+    let f = load_file("tests/samples/search_shard_failures.json");
+    let deserialized = parse::<SearchResponse<Value>>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    assert_eq!(1, deserialized.hits().into_iter().count());
+    assert_eq!(1, deserialized.shards().failed());
+
+    let failure = &deserialized.shard_failures()[0];
+
+    assert_eq!(Some("bank"), failure.index());
+    assert_eq!(Some(2), failure.shard());
+    assert_eq!(Some("KuXXd6nVRaGZaPCUDmhrRw"), failure.node());
+    assert_eq!("query_shard_exception", failure.ty());
+    assert!(failure.reason().unwrap().starts_with("failed to create query"));
+}
+
 #[test]
 fn success_parse_hits_simple() {
     let f = load_file("tests/samples/search_hits_only.json");
@@ -0,0 +1,78 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_snapshot_status_response() {
+    let f = load_file("tests/samples/snapshot_status.json");
+    let deserialized = parse::<SnapshotStatusResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    let snapshot = deserialized.iter().next().unwrap();
+
+    assert_eq!("snapshot_1", snapshot.snapshot());
+    assert_eq!("my_backup", snapshot.repository());
+    assert_eq!(&SnapshotState::Success, snapshot.state());
+    assert_eq!(1, snapshot.shards_stats().failed());
+    assert!(snapshot.has_failed_shards());
+    assert_eq!(5, snapshot.shards_stats().total());
+
+    let (index, status) = snapshot.indices().next().unwrap();
+
+    assert_eq!("index_1", index);
+    assert_eq!(4, status.shards_stats().done());
+}
+
+#[test]
+fn success_parse_snapshot_states() {
+    let states = vec![
+        ("INIT", SnapshotState::Init),
+        ("STARTED", SnapshotState::Started),
+        ("SUCCESS", SnapshotState::Success),
+        ("FAILED", SnapshotState::Failed),
+        ("ABORTED", SnapshotState::Aborted),
+    ];
+
+    for (state, expected) in states {
+        let deserialized: SnapshotState = serde_json::from_value(json!(state)).unwrap();
+
+        assert_eq!(expected, deserialized);
+    }
+}
+
+#[test]
+fn success_parse_unknown_snapshot_state() {
+    let body = json!({
+        "snapshots": [
+            {
+                "snapshot": "snapshot_1",
+                "repository": "my_backup",
+                "state": "WAITING",
+                "shards_stats": {
+                    "initializing": 0,
+                    "started": 5,
+                    "finalizing": 0,
+                    "done": 0,
+                    "failed": 0,
+                    "total": 5
+                }
+            }
+        ]
+    });
+
+    let deserialized = parse::<SnapshotStatusResponse>()
+        .from_slice(StatusCode::OK, serde_json::to_vec(&body).unwrap())
+        .unwrap();
+
+    let snapshot = deserialized.iter().next().unwrap();
+
+    assert_eq!(
+        &SnapshotState::Other("WAITING".to_owned()),
+        snapshot.state()
+    );
+    assert_eq!(0, snapshot.indices().count());
+    assert!(!snapshot.has_failed_shards());
+}
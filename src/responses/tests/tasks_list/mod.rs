@@ -0,0 +1,43 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+use std::time::Duration;
+
+#[test]
+fn success_parse_tasks_list_response() {
+    let f = load_file("tests/samples/tasks_list.json");
+    let deserialized = parse::<TasksListResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    assert_eq!(2, deserialized.tasks().count());
+
+    let task = deserialized
+        .tasks()
+        .find(|task| task.id() == 124)
+        .unwrap();
+
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A:124", task.task_id());
+    assert_eq!("cluster:monitor/tasks/lists[n]", task.action());
+    assert_eq!(Duration::new(0, 47402), task.running_time());
+    assert_eq!(Some("oTUltX4IQMOUUVeiohTt8A:123"), task.parent_task_id());
+
+    let parent = deserialized
+        .tasks()
+        .find(|task| task.id() == 123)
+        .unwrap();
+
+    assert_eq!(None, parent.parent_task_id());
+}
+
+#[test]
+fn success_parse_empty_tasks_list_response() {
+    let deserialized = parse::<TasksListResponse>()
+        .from_slice(StatusCode::OK, r#"{ "nodes": {} }"#.as_bytes())
+        .unwrap();
+
+    assert_eq!(0, deserialized.tasks().count());
+}
@@ -0,0 +1,75 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_valid_query() {
+    let f = load_file("tests/samples/validate_query_valid.json");
+    let deserialized = parse::<ValidateQueryResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    assert!(deserialized.valid());
+    assert_eq!(1, deserialized.shards().unwrap().successful());
+    assert_eq!(0, deserialized.errors().count());
+
+    let explanation = deserialized.explanations().next().unwrap();
+
+    assert_eq!(Some("twitter"), explanation.index());
+    assert_eq!(Some("+user:kimchy #*:*"), explanation.explanation());
+}
+
+#[test]
+fn success_parse_invalid_query() {
+    let f = load_file("tests/samples/validate_query_invalid.json");
+    let deserialized = parse::<ValidateQueryResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    assert!(!deserialized.valid());
+
+    let errors: Vec<_> = deserialized.errors().collect();
+
+    assert_eq!(1, errors.len());
+    assert!(errors[0].contains("QueryShardException"));
+}
+
+#[test]
+fn success_parse_without_explanations() {
+    let body = json!({
+        "valid": true,
+        "_shards": {
+            "total": 1,
+            "successful": 1,
+            "failed": 0
+        }
+    });
+
+    let deserialized = parse::<ValidateQueryResponse>()
+        .from_slice(StatusCode::OK, serde_json::to_vec(&body).unwrap())
+        .unwrap();
+
+    assert!(deserialized.valid());
+    assert_eq!(0, deserialized.explanations().count());
+}
+
+#[test]
+fn success_parse_invalid_query_error() {
+    let f = load_file("tests/samples/validate_query_invalid_error.json");
+    let deserialized = parse::<ValidateQueryResponse>()
+        .from_reader(StatusCode::OK, f)
+        .unwrap();
+
+    assert!(!deserialized.valid());
+    assert_eq!(
+        Some("org.elasticsearch.common.ParsingException: no [query] registered for [foo]"),
+        deserialized.error()
+    );
+
+    let errors: Vec<_> = deserialized.errors().collect();
+
+    assert_eq!(1, errors.len());
+    assert!(errors[0].contains("ParsingException"));
+}
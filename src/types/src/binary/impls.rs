@@ -0,0 +1,209 @@
+use super::mapping::{
+    BinaryFieldType,
+    BinaryMapping,
+};
+use base64;
+use serde::de::{
+    Error,
+    Visitor,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+
+/**
+An Elasticsearch `binary` with a mapping.
+
+The bytes are serialised as a base64 string.
+
+# Examples
+
+Defining a `binary` with a mapping:
+
+```
+# use elastic_types::prelude::*;
+let binary = Binary::<DefaultBinaryMapping>::new(vec![0x62, 0x69, 0x6e]);
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Binary<TMapping>
+where
+    TMapping: BinaryMapping,
+{
+    value: Vec<u8>,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> Binary<TMapping>
+where
+    TMapping: BinaryMapping,
+{
+    /**
+    Creates a new `Binary` with the given mapping.
+
+    # Examples
+
+    Create a new `Binary` from a byte slice:
+
+    ```
+    # use elastic_types::prelude::*;
+    let binary = Binary::<DefaultBinaryMapping>::new(&b"bin"[..]);
+    ```
+    */
+    pub fn new<I>(binary: I) -> Binary<TMapping>
+    where
+        I: Into<Vec<u8>>,
+    {
+        Binary {
+            value: binary.into(),
+            _m: PhantomData,
+        }
+    }
+
+    /**
+    Change the mapping of this binary.
+
+    # Examples
+
+    Change the mapping for a given `Binary`:
+
+    ```
+    # extern crate serde;
+    # #[macro_use]
+    # extern crate elastic_types;
+    # fn main() {
+    # use elastic_types::prelude::*;
+    # #[derive(Default)]
+    # struct MyBinaryMapping;
+    # impl BinaryMapping for MyBinaryMapping { }
+    let binary = Binary::<DefaultBinaryMapping>::new(vec![0x62, 0x69, 0x6e]);
+
+    let binary: Binary<MyBinaryMapping> = Binary::remap(binary);
+    # }
+    ```
+    */
+    pub fn remap<TNewMapping>(binary: Binary<TMapping>) -> Binary<TNewMapping>
+    where
+        TNewMapping: BinaryMapping,
+    {
+        Binary::<TNewMapping>::new(binary.value)
+    }
+
+    /** Get the bytes of this binary. */
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.value
+    }
+}
+
+impl<TMapping> BinaryFieldType<TMapping> for Binary<TMapping> where TMapping: BinaryMapping {}
+
+impl_mapping_type!(Vec<u8>, Binary, BinaryMapping);
+
+impl<TMapping> Serialize for Binary<TMapping>
+where
+    TMapping: BinaryMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&base64::encode(&self.value))
+    }
+}
+
+impl<'de, TMapping> Deserialize<'de> for Binary<TMapping>
+where
+    TMapping: BinaryMapping,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Binary<TMapping>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BinaryVisitor<TMapping> {
+            _m: PhantomData<TMapping>,
+        }
+
+        impl<'de, TMapping> Visitor<'de> for BinaryVisitor<TMapping>
+        where
+            TMapping: BinaryMapping,
+        {
+            type Value = Binary<TMapping>;
+
+            fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(formatter, "a json string containing base64 encoded bytes")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Binary<TMapping>, E>
+            where
+                E: Error,
+            {
+                let de = try!(base64::decode(v).map_err(|e| E::custom(e.to_string())));
+
+                Ok(Binary::<TMapping>::new(de))
+            }
+        }
+
+        deserializer.deserialize_str(BinaryVisitor::<TMapping> { _m: PhantomData })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use prelude::*;
+
+    #[derive(Default)]
+    struct MyBinaryMapping;
+    impl BinaryMapping for MyBinaryMapping {}
+
+    #[test]
+    fn can_change_binary_mapping() {
+        fn takes_custom_mapping(_: Binary<MyBinaryMapping>) -> bool {
+            true
+        }
+
+        let binary: Binary<DefaultBinaryMapping> = Binary::new(vec![1, 2, 3]);
+
+        assert!(takes_custom_mapping(Binary::remap(binary)));
+    }
+
+    #[test]
+    fn serialise_elastic_binary() {
+        let binary: Binary<DefaultBinaryMapping> = Binary::new(&b"elastic"[..]);
+
+        let ser = serde_json::to_string(&binary).unwrap();
+
+        assert_eq!(r#""ZWxhc3RpYw==""#, ser);
+    }
+
+    #[test]
+    fn deserialise_elastic_binary() {
+        let binary: Binary<DefaultBinaryMapping> = serde_json::from_str(r#""ZWxhc3RpYw==""#).unwrap();
+
+        assert_eq!(b"elastic".to_vec(), binary);
+    }
+
+    #[test]
+    fn deserialise_elastic_binary_invalid() {
+        let binary = serde_json::from_str::<Binary<DefaultBinaryMapping>>(r#""not base64!""#);
+
+        assert!(binary.is_err());
+    }
+
+    #[test]
+    fn serialise_elastic_binary_roundtrip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let binary: Binary<DefaultBinaryMapping> = Binary::new(bytes.clone());
+
+        let ser = serde_json::to_string(&binary).unwrap();
+        let de: Binary<DefaultBinaryMapping> = serde_json::from_str(&ser).unwrap();
+
+        assert_eq!(bytes, de.into_bytes());
+    }
+}
@@ -0,0 +1,186 @@
+/*! Mapping for the Elasticsearch `binary` type. */
+
+/** A field that will be mapped as a `binary`. */
+pub trait BinaryFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `binary` type.
+
+Custom mappings can be defined by implementing `BinaryMapping`.
+
+# Examples
+
+Define a custom `BinaryMapping`:
+
+```
+# extern crate serde;
+# #[macro_use]
+# extern crate elastic_types;
+# use elastic_types::prelude::*;
+# fn main() {
+#[derive(Default)]
+struct MyBinaryMapping;
+impl BinaryMapping for MyBinaryMapping {
+    //Overload the mapping functions here
+    fn store() -> Option<bool> {
+        Some(true)
+    }
+}
+# }
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use]
+# extern crate json_str;
+# #[macro_use]
+# extern crate elastic_types;
+# extern crate serde;
+# extern crate serde_json;
+# use elastic_types::prelude::*;
+# #[derive(Default)]
+# struct MyBinaryMapping;
+# impl BinaryMapping for MyBinaryMapping {
+#     //Overload the mapping functions here
+#     fn store() -> Option<bool> {
+#         Some(true)
+#     }
+# }
+# fn main() {
+# let mapping = elastic_types::derive::standalone_field_ser(MyBinaryMapping).unwrap();
+# let json = json_str!(
+{
+    "type": "binary",
+    "store": true
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+*/
+pub trait BinaryMapping {
+    /**
+    Should the field be stored on disk in a column-stride fashion,
+    so that it can later be used for sorting, aggregations, or scripting?
+    Accepts `true` or `false` (default).
+    */
+    fn doc_values() -> Option<bool> {
+        None
+    }
+
+    /**
+    Whether the field value should be stored and retrievable separately from the `_source` field.
+    Accepts `true` or `false` (default).
+    */
+    fn store() -> Option<bool> {
+        None
+    }
+}
+
+/** Default mapping for `binary`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultBinaryMapping;
+impl BinaryMapping for DefaultBinaryMapping {}
+
+mod private {
+    use super::{
+        BinaryFieldType,
+        BinaryMapping,
+    };
+    use private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::ser::SerializeStruct;
+    use serde::{
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct BinaryPivot;
+
+    impl<TField, TMapping> FieldType<TMapping, BinaryPivot> for TField
+    where
+        TMapping: BinaryMapping,
+        TField: BinaryFieldType<TMapping> + Serialize,
+    {
+    }
+
+    impl<TMapping> FieldMapping<BinaryPivot> for TMapping
+    where
+        TMapping: BinaryMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, BinaryPivot>;
+
+        fn data_type() -> &'static str {
+            "binary"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, BinaryPivot>
+    where
+        TMapping: FieldMapping<BinaryPivot> + BinaryMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = try!(serializer.serialize_struct("mapping", 3));
+
+            try!(state.serialize_field("type", TMapping::data_type()));
+
+            ser_field!(state, "doc_values", TMapping::doc_values());
+            ser_field!(state, "store", TMapping::store());
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use prelude::*;
+    use private::field;
+
+    #[derive(Default, Clone)]
+    pub struct MyBinaryMapping;
+    impl BinaryMapping for MyBinaryMapping {
+        fn doc_values() -> Option<bool> {
+            Some(true)
+        }
+
+        fn store() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = serde_json::to_string(&field::serialize(DefaultBinaryMapping)).unwrap();
+
+        let expected = json_str!({
+            "type": "binary"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_custom() {
+        let ser = serde_json::to_string(&field::serialize(MyBinaryMapping)).unwrap();
+
+        let expected = json_str!({
+            "type": "binary",
+            "doc_values": true,
+            "store": true
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
@@ -0,0 +1,55 @@
+/*!
+Implementation of the Elasticsearch `binary` type.
+
+Binary values are sent to Elasticsearch as base64 strings.
+The `Binary<M>` type wraps a `Vec<u8>` and takes care of encoding and decoding the bytes.
+
+# Examples
+
+For defining your own binary mapping, see [mapping details](mapping/trait.BinaryMapping.html#derive-mapping).
+
+Map with a default `binary`:
+
+```
+# use elastic_types::prelude::*;
+struct MyType {
+    pub field: Binary<DefaultBinaryMapping>
+}
+```
+
+Map with a custom `binary`:
+
+```
+# extern crate serde;
+# #[macro_use]
+# extern crate elastic_types;
+# fn main() {
+# use elastic_types::prelude::*;
+# #[derive(Default)]
+# struct MyBinaryMapping;
+# impl BinaryMapping for MyBinaryMapping { }
+struct MyType {
+    pub field: Binary<MyBinaryMapping>
+}
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/binary.html)
+*/
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `binary` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::impls::*;
+    pub use super::mapping::*;
+}
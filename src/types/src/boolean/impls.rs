@@ -5,6 +5,7 @@ use super::mapping::{
 };
 use serde::de::{
     Error,
+    Unexpected,
     Visitor,
 };
 use serde::{
@@ -132,7 +133,7 @@ where
             type Value = Boolean<TMapping>;
 
             fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                write!(formatter, "a json boolean")
+                write!(formatter, "a json boolean or a string containing a boolean")
             }
 
             fn visit_bool<E>(self, v: bool) -> Result<Boolean<TMapping>, E>
@@ -141,6 +142,18 @@ where
             {
                 Ok(Boolean::<TMapping>::new(v))
             }
+
+            // Elasticsearch also accepts `"true"` and `"false"`, and treats an empty string as `false`
+            fn visit_str<E>(self, v: &str) -> Result<Boolean<TMapping>, E>
+            where
+                E: Error,
+            {
+                match v {
+                    "true" => Ok(Boolean::<TMapping>::new(true)),
+                    "false" | "" => Ok(Boolean::<TMapping>::new(false)),
+                    _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+                }
+            }
         }
 
         deserializer.deserialize_any(BooleanVisitor::<TMapping> { _m: PhantomData })
@@ -184,4 +197,22 @@ mod tests {
         assert_eq!(true, boolean);
     }
 
+    #[test]
+    fn deserialise_elastic_boolean_from_string() {
+        let boolean: Boolean<DefaultBooleanMapping> = serde_json::from_str(r#""true""#).unwrap();
+        assert_eq!(true, boolean);
+
+        let boolean: Boolean<DefaultBooleanMapping> = serde_json::from_str(r#""false""#).unwrap();
+        assert_eq!(false, boolean);
+
+        let boolean: Boolean<DefaultBooleanMapping> = serde_json::from_str(r#""""#).unwrap();
+        assert_eq!(false, boolean);
+    }
+
+    #[test]
+    fn deserialise_elastic_boolean_invalid_string() {
+        let boolean = serde_json::from_str::<Boolean<DefaultBooleanMapping>>(r#""yes""#);
+
+        assert!(boolean.is_err());
+    }
 }
@@ -78,7 +78,7 @@ pub trait BooleanMapping {
         None
     }
 
-    /** Should the field be searchable? Accepts `not_analyzed` (default) and `no`. */
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
     fn index() -> Option<bool> {
         None
     }
@@ -10,6 +10,10 @@ use chrono::{
     NaiveTime,
     Utc,
 };
+use serde::{
+    Serialize,
+    Serializer,
+};
 use std::borrow::Borrow;
 use std::error::Error;
 use std::fmt::{
@@ -37,7 +41,14 @@ impl DateValue {
         DateValue(Utc::now())
     }
 
-    /** Construct a `DateValue` from individual parts. */
+    /**
+    Construct a `DateValue` from individual parts.
+
+    # Panics
+
+    This method will panic if any of the parts are out of range.
+    Use `build_checked` to get an error instead.
+    */
     pub fn build(
         year: i32,
         month: u32,
@@ -54,6 +65,27 @@ impl DateValue {
 
         DateValue(date)
     }
+
+    /** Construct a `DateValue` from individual parts, returning an error if any of them are out of range. */
+    pub fn build_checked(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        milli: u32,
+    ) -> Result<Self, ParseError> {
+        let ndate = NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| format!("invalid date {}-{}-{}", year, month, day))?;
+        let ntime = NaiveTime::from_hms_milli_opt(hour, minute, second, milli).ok_or_else(|| {
+            format!("invalid time {}:{}:{}.{}", hour, minute, second, milli)
+        })?;
+
+        let date = ChronoDateTime::from_utc(NaiveDateTime::new(ndate, ntime), Utc);
+
+        Ok(DateValue(date))
+    }
 }
 
 impl<TFormat> From<FormattableDateValue<TFormat>> for DateValue {
@@ -127,6 +159,13 @@ where
 
         Ok(FormattableDateValue::from(date))
     }
+
+    /** Parse a date value using the generic format, assuming the given time if the input doesn't contain one. */
+    pub fn parse_with_default_time(date: &str, time: NaiveTime) -> Result<Self, ParseError> {
+        let date = TFormat::parse_with_default_time(date, time)?;
+
+        Ok(FormattableDateValue::from(date))
+    }
 }
 
 impl<TFormat> From<DateValue> for FormattableDateValue<TFormat> {
@@ -191,6 +230,10 @@ and must contain a valid [format string](http://www.joda.org/joda-time/apidocs/o
 
 > NOTE: Only a small subset of the Joda time format is supported.
 
+Fractional seconds are formatted with `.SSS` for milliseconds, `.SSSSSS` for microseconds or `.SSSSSSSSS` for nanoseconds.
+Any precision beyond that is dropped when a date is formatted, so leave fractional seconds out entirely to format whole seconds,
+like the `BasicDateTimeNoMillis` format.
+
 You can customise the indexed format name by adding an `#[elastic(date_format_name)]` attribute:
 
 ```
@@ -206,12 +249,62 @@ You can customise the indexed format name by adding an `#[elastic(date_format_na
 struct MyFormat;
 # }
 ```
+
+Multiple formats can be given by joining them with `||`, like in an Elasticsearch mapping.
+Dates are parsed with each format in turn and formatted using the first one:
+
+```
+# #[macro_use]
+# extern crate elastic_types;
+# #[macro_use]
+# extern crate elastic_types_derive;
+# extern crate chrono;
+# use elastic_types::prelude::*;
+# fn main() {
+#[derive(Default, ElasticDateFormat)]
+#[elastic(date_format="yyyy-MM-dd'T'HH:mm:ssZ||yyyy-MM-dd")]
+struct MyFormat;
+# }
+```
+
+Format strings are checked when the derive is expanded, so an unsupported token is a compile error:
+
+```compile_fail
+# #[macro_use]
+# extern crate elastic_types;
+# #[macro_use]
+# extern crate elastic_types_derive;
+# extern crate chrono;
+# use elastic_types::prelude::*;
+# fn main() {
+#[derive(Default, ElasticDateFormat)]
+#[elastic(date_format="yyyy-MM-dd EEE")]
+struct MyFormat;
+# }
+```
 */
 pub trait DateFormat {
     /** Parses a date string to a `chrono::DateTime<Utc>` result. */
     fn parse(date: &str) -> Result<DateValue, ParseError>;
 
-    /** Formats a given `chrono::DateTime<Utc>` as a string. */
+    /**
+    Parses a date string to a `chrono::DateTime<Utc>` result, assuming the given time if the string doesn't contain one.
+
+    `parse` assumes midnight for formats without a time component.
+    The default implementation ignores the given time and calls `parse`, which is fine for formats that always include a time.
+    */
+    fn parse_with_default_time(date: &str, time: NaiveTime) -> Result<DateValue, ParseError> {
+        let _ = time;
+
+        Self::parse(date)
+    }
+
+    /**
+    Formats a given `chrono::DateTime<Utc>` as a string.
+
+    Formats that return a `FormattedDate` from an `i64`, like `epoch_millis`, are serialised as a number.
+    Anything else is serialised as a string.
+    */
     fn format<'a>(date: &'a DateValue) -> FormattedDate<'a>;
 
     /**
@@ -260,6 +353,18 @@ impl<'a> Display for FormattedDate<'a> {
     }
 }
 
+impl<'a> Serialize for FormattedDate<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.inner {
+            FormattedDateInner::Number(number) => serializer.serialize_i64(number),
+            _ => serializer.collect_str(&self.inner),
+        }
+    }
+}
+
 impl<'a> From<DelayedFormat<IntoIter<Item<'a>>>> for FormattedDate<'a> {
     fn from(formatted: DelayedFormat<IntoIter<Item<'a>>>) -> Self {
         FormattedDate {
@@ -284,10 +389,15 @@ impl<'a> From<i64> for FormattedDate<'a> {
     }
 }
 
-/** Represents an error encountered during parsing. */
+/**
+Represents an error encountered during parsing.
+
+Errors from formats derived with `ElasticDateFormat` also carry the input and the position parsing failed at.
+*/
 #[derive(Debug)]
 pub struct ParseError {
     kind: ParseErrorKind,
+    location: Option<ParseLocation>,
 }
 
 #[derive(Debug)]
@@ -296,11 +406,62 @@ enum ParseErrorKind {
     Other(String),
 }
 
+#[derive(Debug)]
+struct ParseLocation {
+    input: String,
+    position: usize,
+    expected: Option<String>,
+}
+
+impl ParseError {
+    pub(crate) fn at_position(
+        err: chrono::ParseError,
+        input: &str,
+        position: usize,
+        expected: Option<String>,
+    ) -> Self {
+        ParseError {
+            kind: ParseErrorKind::Chrono(err),
+            location: Some(ParseLocation {
+                input: input.to_owned(),
+                position: position,
+                expected: expected,
+            }),
+        }
+    }
+
+    /** The input that failed to parse, if it's known. */
+    pub fn input(&self) -> Option<&str> {
+        self.location.as_ref().map(|location| &location.input[..])
+    }
+
+    /** The byte position in the input where parsing failed, if it's known. */
+    pub fn position(&self) -> Option<usize> {
+        self.location.as_ref().map(|location| location.position)
+    }
+}
+
 impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        match self.kind {
-            ParseErrorKind::Chrono(ref err) => write!(f, "Chrono error: {}", err),
-            ParseErrorKind::Other(ref err) => write!(f, "Error: {}", err),
+        match (&self.kind, &self.location) {
+            (
+                _,
+                &Some(ParseLocation {
+                    ref input,
+                    position,
+                    expected: Some(ref expected),
+                }),
+            ) => write!(f, "expected {} at position {} in {:?}", expected, position, input),
+            (
+                &ParseErrorKind::Chrono(ref err),
+                &Some(ParseLocation {
+                    ref input,
+                    position,
+                    ..
+                }),
+            ) => write!(f, "Chrono error: {} at position {} in {:?}", err, position, input),
+            (&ParseErrorKind::Chrono(ref err), _) => write!(f, "Chrono error: {}", err),
+            (&ParseErrorKind::Other(ref err), _) => write!(f, "Error: {}", err),
         }
     }
 }
@@ -325,6 +486,7 @@ impl From<chrono::ParseError> for ParseError {
     fn from(err: chrono::ParseError) -> ParseError {
         ParseError {
             kind: ParseErrorKind::Chrono(err),
+            location: None,
         }
     }
 }
@@ -333,6 +495,7 @@ impl From<String> for ParseError {
     fn from(err: String) -> ParseError {
         ParseError {
             kind: ParseErrorKind::Other(err),
+            location: None,
         }
     }
 }
@@ -6,11 +6,17 @@ use super::{
 };
 use chrono::{
     DateTime,
+    NaiveDate,
     NaiveDateTime,
+    NaiveTime,
     Timelike,
     Utc,
 };
+use std::any::TypeId;
+use std::collections::HashMap;
 use std::error::Error;
+use std::marker::PhantomData;
+use std::sync::Mutex;
 
 /** The default `date` format (`BasicDateTime`). */
 pub type DefaultDateFormat = BasicDateTime;
@@ -92,6 +98,208 @@ impl DateFormat for EpochMillis {
     }
 }
 
+/**
+Format for `epoch_second`.
+
+Takes a string of whole seconds since the epoch and converts to a `DateTime`.
+Any fraction of a second is dropped when a date is formatted, so this is a good choice for timestamps from sources that only record seconds.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct EpochSecond;
+
+impl DateFormat for EpochSecond {
+    fn name() -> &'static str {
+        "epoch_second"
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        let secs = date
+            .parse::<i64>()
+            .map_err(|e| e.description().to_string())?;
+
+        let date = NaiveDateTime::from_timestamp_opt(secs, 0)
+            .ok_or_else(|| format!("timestamp '{}' is out of range", secs))?;
+
+        Ok(DateTime::from_utc(date, Utc).into())
+    }
+
+    fn format<'a>(date: &'a DateValue) -> FormattedDate<'a> {
+        date.timestamp().into()
+    }
+}
+
+/**
+Format for `strict_date_optional_time`.
+
+This is the format Elasticsearch uses for a `date` field when no format is given in the mapping,
+so it's a good choice for documents indexed by other tools.
+A date like `2015-05-13` is parsed with an optional time like `T14:55:43.778Z`, which can have optional seconds and fractional seconds.
+The month and day can be left off a date without a time, like `2015` or `2015-05`, and default to the first of the year or month.
+Times without an offset are assumed to be in `Utc`.
+Dates are always formatted with a time and milliseconds, like `2015-05-13T14:55:43.778Z`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DateOptionalTime;
+
+impl DateFormat for DateOptionalTime {
+    fn name() -> &'static str {
+        "strict_date_optional_time"
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        Self::parse_with_default_time(date, NaiveTime::from_hms(0, 0, 0))
+    }
+
+    fn parse_with_default_time(date: &str, time: NaiveTime) -> Result<DateValue, ParseError> {
+        if !date.contains('T') {
+            // A missing month or day is the first of the year or month
+            let date = match date.matches('-').count() {
+                0 => NaiveDate::parse_from_str(&format!("{}-01-01", date), "%Y-%m-%d")?,
+                1 => NaiveDate::parse_from_str(&format!("{}-01", date), "%Y-%m-%d")?,
+                _ => NaiveDate::parse_from_str(date, "%Y-%m-%d")?,
+            };
+
+            return Ok(DateTime::from_utc(date.and_time(time), Utc).into());
+        }
+
+        if let Ok(date) = DateTime::parse_from_rfc3339(date) {
+            return Ok(date.with_timezone(&Utc).into());
+        }
+
+        // RFC3339 needs seconds and an offset, but Elasticsearch doesn't
+        let offset = ["%Y-%m-%dT%H:%M:%S%.f%z", "%Y-%m-%dT%H:%M%z"]
+            .iter()
+            .filter_map(|fmt| DateTime::parse_from_str(date, fmt).ok())
+            .next();
+
+        if let Some(date) = offset {
+            return Ok(date.with_timezone(&Utc).into());
+        }
+
+        let naive = date.trim_right_matches('Z');
+
+        ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"]
+            .iter()
+            .filter_map(|fmt| NaiveDateTime::parse_from_str(naive, fmt).ok())
+            .next()
+            .map(|date| DateTime::from_utc(date, Utc).into())
+            .ok_or_else(|| ParseError::from(format!("'{}' is not a date with an optional time", date)))
+    }
+
+    fn format<'a>(date: &'a DateValue) -> FormattedDate<'a> {
+        date.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string().into()
+    }
+}
+
+/**
+A format that tries to parse a date with each of a tuple of formats in turn.
+
+This is useful for indices that contain dates written with different formats over time.
+Dates are always formatted using the first format, and the name sent to Elasticsearch is each format name joined by `||`.
+If none of the formats can parse a date then the error from the first format is returned.
+
+# Examples
+
+Parse dates as `basic_date_time`, falling back to `epoch_millis`:
+
+```
+# extern crate serde_json;
+# extern crate elastic_types;
+# use elastic_types::prelude::*;
+# fn main() {
+type MyFormat = MultiFormat<(BasicDateTime, EpochMillis)>;
+
+let date: Date<DefaultDateMapping<MyFormat>> = serde_json::from_str(r#""20150703T145502.478Z""#).unwrap();
+let fallback: Date<DefaultDateMapping<MyFormat>> = serde_json::from_str("1435935302478").unwrap();
+
+assert_eq!(date, fallback);
+assert_eq!("basic_date_time||epoch_millis", MyFormat::name());
+# }
+```
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct MultiFormat<TFormats>(PhantomData<TFormats>);
+
+/**
+Get the name for a combination of formats.
+
+Format names need to be `&'static str`s, so the joined name is leaked the first time it's needed and shared by all threads.
+*/
+fn multi_format_name<TFormat>(name: impl FnOnce() -> String) -> &'static str
+where
+    TFormat: 'static,
+{
+    lazy_static! {
+        static ref NAMES: Mutex<HashMap<TypeId, &'static str>> = Mutex::new(HashMap::new());
+    }
+
+    let mut names = NAMES.lock().unwrap_or_else(|e| e.into_inner());
+
+    *names
+        .entry(TypeId::of::<TFormat>())
+        .or_insert_with(|| Box::leak(name().into_boxed_str()))
+}
+
+macro_rules! impl_multi_format {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first, $($rest),*> DateFormat for MultiFormat<($first, $($rest),*)>
+        where
+            $first: DateFormat + 'static,
+            $($rest: DateFormat + 'static),*
+        {
+            fn parse(date: &str) -> Result<DateValue, ParseError> {
+                let first = $first::parse(date);
+
+                if first.is_ok() {
+                    return first;
+                }
+
+                $(
+                    if let Ok(date) = $rest::parse(date) {
+                        return Ok(date);
+                    }
+                )*
+
+                first
+            }
+
+            fn parse_with_default_time(date: &str, time: NaiveTime) -> Result<DateValue, ParseError> {
+                let first = $first::parse_with_default_time(date, time);
+
+                if first.is_ok() {
+                    return first;
+                }
+
+                $(
+                    if let Ok(date) = $rest::parse_with_default_time(date, time) {
+                        return Ok(date);
+                    }
+                )*
+
+                first
+            }
+
+            fn format<'a>(date: &'a DateValue) -> FormattedDate<'a> {
+                $first::format(date)
+            }
+
+            fn name() -> &'static str {
+                multi_format_name::<Self>(|| [$first::name(), $($rest::name()),*].join("||"))
+            }
+        }
+    };
+}
+
+impl_multi_format!(TFirst, TSecond);
+impl_multi_format!(TFirst, TSecond, TThird);
+impl_multi_format!(TFirst, TSecond, TThird, TFourth);
+
 #[cfg(test)]
 mod tests {
     use chrono::{
@@ -104,6 +312,7 @@ mod tests {
         ParseError,
     };
     use prelude::*;
+    use serde_json;
 
     #[test]
     fn chrono() {
@@ -176,6 +385,150 @@ mod tests {
         assert_eq!("20150703T145502.478Z", &fmtd);
     }
 
+    #[test]
+    fn basic_date_time_comma_separator() {
+        let dot = parse::<DefaultDateMapping<BasicDateTime>>("20150703T145502.478Z").unwrap();
+        let comma = parse::<DefaultDateMapping<BasicDateTime>>("20150703T145502,478Z").unwrap();
+
+        assert_eq!(dot, comma);
+
+        let fmtd = format(&comma).to_string();
+        assert_eq!("20150703T145502.478Z", &fmtd);
+    }
+
+    #[test]
+    fn custom_format_comma_separator() {
+        #[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+        #[elastic(date_format = "yyyy-MM-dd'T'HH:mm:ss.SSSZ")]
+        struct MillisFormat;
+
+        let dot = parse::<DefaultDateMapping<MillisFormat>>("2015-05-13T00:00:00.123Z").unwrap();
+        let comma = parse::<DefaultDateMapping<MillisFormat>>("2015-05-13T00:00:00,123Z").unwrap();
+
+        assert_eq!(dot, comma);
+        assert_eq!(123u32, comma.nanosecond() / 1000000);
+    }
+
+    #[test]
+    fn custom_format_comma_literal() {
+        #[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+        #[elastic(date_format = "yyyy-MM-dd','HH:mm:ss.SSSZ")]
+        struct CommaFormat;
+
+        let date = parse::<DefaultDateMapping<CommaFormat>>("2015-05-13,14:55:43,778Z").unwrap();
+
+        assert_eq!((14u32, 778u32), (date.hour(), date.nanosecond() / 1000000));
+        assert_eq!("2015-05-13,14:55:43.778Z", format(&date).to_string());
+    }
+
+    #[test]
+    fn custom_format_fractional_precision() {
+        #[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+        #[elastic(date_format = "yyyyMMdd'T'HHmmss.SSSSSSZ")]
+        struct MicrosFormat;
+
+        #[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+        #[elastic(date_format = "yyyyMMdd'T'HHmmss.SSSSSSSSSZ")]
+        struct NanosFormat;
+
+        let nanos = parse::<DefaultDateMapping<NanosFormat>>("20150703T145502.478123456Z").unwrap();
+
+        assert_eq!(478123456u32, nanos.nanosecond());
+        assert_eq!("20150703T145502.478123456Z", format(&nanos).to_string());
+
+        let micros: Date<DefaultDateMapping<MicrosFormat>> = Date::remap(nanos.clone());
+        assert_eq!("20150703T145502.478123Z", format(&micros).to_string());
+
+        let millis: Date<DefaultDateMapping<BasicDateTime>> = Date::remap(nanos.clone());
+        assert_eq!("20150703T145502.478Z", format(&millis).to_string());
+
+        let secs: Date<DefaultDateMapping<BasicDateTimeNoMillis>> = Date::remap(nanos);
+        assert_eq!("20150703T145502Z", format(&secs).to_string());
+    }
+
+    #[test]
+    fn custom_format_multiple_patterns() {
+        #[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+        #[elastic(date_format = "yyyy-MM-dd'T'HH:mm:ssZ || yyyy-MM-dd")]
+        struct JoinedFormat;
+
+        #[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+        #[elastic(date_format = "yyyy-MM-dd'T'HH:mm:ssZ")]
+        #[elastic(date_format = "yyyy-MM-dd")]
+        struct RepeatedFormat;
+
+        assert_eq!("yyyy-MM-dd'T'HH:mm:ssZ||yyyy-MM-dd", JoinedFormat::name());
+        assert_eq!("yyyy-MM-dd'T'HH:mm:ssZ||yyyy-MM-dd", RepeatedFormat::name());
+
+        let date_time = parse::<DefaultDateMapping<JoinedFormat>>("2015-05-13T14:55:43Z").unwrap();
+        let date = parse::<DefaultDateMapping<JoinedFormat>>("2015-05-13").unwrap();
+
+        assert_eq!((14u32, 55u32, 43u32), (date_time.hour(), date_time.minute(), date_time.second()));
+        assert_eq!((2015i32, 5u32, 13u32, 0u32), (date.year(), date.month(), date.day(), date.hour()));
+
+        assert_eq!("2015-05-13T00:00:00Z", format(&date).to_string());
+
+        let date = parse::<DefaultDateMapping<RepeatedFormat>>("2015-05-13").unwrap();
+        assert_eq!("2015-05-13T00:00:00Z", format(&date).to_string());
+    }
+
+    #[test]
+    fn custom_format_multiple_patterns_err() {
+        #[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+        #[elastic(date_format = "yyyy-MM-dd'T'HH:mm:ssZ||yyyy-MM-dd")]
+        struct JoinedFormat;
+
+        let err = parse::<DefaultDateMapping<JoinedFormat>>("13/05/2015").unwrap_err();
+
+        assert_eq!(Some("13/05/2015"), err.input());
+    }
+
+    #[test]
+    fn basic_date_time_no_millis_rejects_comma() {
+        let result = parse::<DefaultDateMapping<BasicDateTimeNoMillis>>("20150703T145502,478Z");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_error_position_missing_literal() {
+        let err = parse::<DefaultDateMapping<BasicDateTimeNoMillis>>("20151126145543").unwrap_err();
+
+        assert_eq!(Some("20151126145543"), err.input());
+        assert_eq!(Some(8), err.position());
+        assert_eq!(
+            r#"expected 'T' at position 8 in "20151126145543""#,
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_error_position_out_of_range() {
+        let err = parse::<DefaultDateMapping<BasicDateTimeNoMillis>>("20151326T145543Z").unwrap_err();
+
+        assert_eq!(Some(4), err.position());
+        assert_eq!(
+            r#"expected a month at position 4 in "20151326T145543Z""#,
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_error_position_trailing_input() {
+        let err = parse::<DefaultDateMapping<BasicDateTimeNoMillis>>("20151126T145543Zabc").unwrap_err();
+
+        assert_eq!(Some(16), err.position());
+        assert!(err.to_string().ends_with(r#"at position 16 in "20151126T145543Zabc""#));
+    }
+
+    #[test]
+    fn parse_error_position_deserialise() {
+        let err = serde_json::from_str::<Date<DefaultDateMapping<BasicDateTimeNoMillis>>>(r#""20151126145543""#)
+            .unwrap_err();
+
+        assert!(err.to_string().contains(r#"expected 'T' at position 8 in "20151126145543""#));
+    }
+
     #[test]
     fn basic_date_time_name() {
         assert_eq!("basic_date_time", BasicDateTime::name());
@@ -375,6 +728,207 @@ mod tests {
         assert_eq!("0", &fmtd);
     }
 
+    #[test]
+    fn epoch_second_name() {
+        assert_eq!("epoch_second", EpochSecond::name());
+    }
+
+    #[test]
+    fn epoch_second() {
+        let date = parse::<DefaultDateMapping<EpochSecond>>("1435935302").unwrap();
+
+        assert_eq!(
+            (2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 0u32),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second(),
+                date.nanosecond()
+            )
+        );
+
+        let fmtd = format(&date).to_string();
+        assert_eq!("1435935302", &fmtd);
+    }
+
+    #[test]
+    fn epoch_second_minus() {
+        let date = parse::<DefaultDateMapping<EpochSecond>>("-8031171899").unwrap();
+
+        assert_eq!(
+            (1715i32, 7u32, 3u32, 14u32, 55u32, 1u32),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second()
+            )
+        );
+
+        let fmtd = format(&date).to_string();
+        assert_eq!("-8031171899", &fmtd);
+    }
+
+    #[test]
+    fn epoch_second_deserialise_number() {
+        let date: Date<DefaultDateMapping<EpochSecond>> = serde_json::from_str("1431521708").unwrap();
+
+        assert_eq!("1431521708", format(&date).to_string());
+        assert_eq!("1431521708", serde_json::to_string(&date).unwrap());
+    }
+
+    #[test]
+    fn date_optional_time_name() {
+        assert_eq!("strict_date_optional_time", DateOptionalTime::name());
+    }
+
+    #[test]
+    fn date_optional_time() {
+        let date = parse::<DefaultDateMapping<DateOptionalTime>>("2015-05-13T14:55:43.778Z").unwrap();
+
+        assert_eq!(
+            (2015i32, 5u32, 13u32, 14u32, 55u32, 43u32, 778u32),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second(),
+                date.nanosecond() / 1000000
+            )
+        );
+
+        let fmtd = format(&date).to_string();
+        assert_eq!("2015-05-13T14:55:43.778Z", &fmtd);
+    }
+
+    #[test]
+    fn date_optional_time_no_time() {
+        let date = parse::<DefaultDateMapping<DateOptionalTime>>("2015-05-13").unwrap();
+
+        assert_eq!(
+            (2015i32, 5u32, 13u32, 0u32, 0u32, 0u32),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second()
+            )
+        );
+
+        let fmtd = format(&date).to_string();
+        assert_eq!("2015-05-13T00:00:00.000Z", &fmtd);
+    }
+
+    #[test]
+    fn date_optional_time_partial_time() {
+        let expected = parse::<DefaultDateMapping<DateOptionalTime>>("2015-05-13T14:55:00.000Z").unwrap();
+
+        for date in &["2015-05-13T14:55:00Z", "2015-05-13T14:55:00", "2015-05-13T14:55"] {
+            let date = parse::<DefaultDateMapping<DateOptionalTime>>(date).unwrap();
+
+            assert_eq!(*expected, *date);
+        }
+    }
+
+    #[test]
+    fn date_optional_time_offset() {
+        let date = parse::<DefaultDateMapping<DateOptionalTime>>("2015-05-13T14:55:43.778+10:00").unwrap();
+
+        let fmtd = format(&date).to_string();
+        assert_eq!("2015-05-13T04:55:43.778Z", &fmtd);
+    }
+
+    #[test]
+    fn date_optional_time_partial_date() {
+        let year = parse::<DefaultDateMapping<DateOptionalTime>>("2015").unwrap();
+        let year_month = parse::<DefaultDateMapping<DateOptionalTime>>("2015-05").unwrap();
+
+        assert_eq!("2015-01-01T00:00:00.000Z", format(&year).to_string());
+        assert_eq!("2015-05-01T00:00:00.000Z", format(&year_month).to_string());
+    }
+
+    #[test]
+    fn date_optional_time_offset_no_seconds() {
+        let expected = parse::<DefaultDateMapping<DateOptionalTime>>("2015-05-13T04:55:00.000Z").unwrap();
+
+        for date in &["2015-05-13T14:55+10:00", "2015-05-13T14:55+1000", "2015-05-13T14:55:00+1000"] {
+            let date = parse::<DefaultDateMapping<DateOptionalTime>>(date).unwrap();
+
+            assert_eq!(*expected, *date);
+        }
+    }
+
+    #[test]
+    fn date_optional_time_invalid() {
+        assert!(parse::<DefaultDateMapping<DateOptionalTime>>("2015-05-13T").is_err());
+        assert!(parse::<DefaultDateMapping<DateOptionalTime>>("13/05/2015").is_err());
+        assert!(parse::<DefaultDateMapping<DateOptionalTime>>("2015-13").is_err());
+    }
+
+    #[test]
+    fn date_optional_time_deserialise() {
+        let date: Date<DefaultDateMapping<DateOptionalTime>> = serde_json::from_str(r#""2015-05-13""#).unwrap();
+
+        assert_eq!(r#""2015-05-13T00:00:00.000Z""#, serde_json::to_string(&date).unwrap());
+    }
+
+    #[test]
+    fn epoch_second_fractional_is_err() {
+        let date = parse::<DefaultDateMapping<EpochSecond>>("1431521708.5");
+
+        assert!(date.is_err());
+    }
+
+    #[test]
+    fn epoch_second_remap_keeps_millis() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 12, 55, 8, 886);
+
+        let secs: Date<DefaultDateMapping<EpochSecond>> = Date::remap(date.clone());
+        assert_eq!("1431521708", format(&secs).to_string());
+
+        let millis: Date<DefaultDateMapping<EpochMillis>> = Date::remap(secs);
+        assert_eq!("1431521708886", format(&millis).to_string());
+        assert_eq!(*date, *millis);
+    }
+
+    #[test]
+    fn multi_format_name() {
+        assert_eq!(
+            "basic_date_time||epoch_millis||epoch_second",
+            MultiFormat::<(BasicDateTime, EpochMillis, EpochSecond)>::name()
+        );
+    }
+
+    #[test]
+    fn multi_format_parse_fallback() {
+        type MyFormat = MultiFormat<(BasicDateTime, BasicDateTimeNoMillis, EpochMillis)>;
+
+        let first = parse::<DefaultDateMapping<MyFormat>>("20150703T145502.478Z").unwrap();
+        let second = parse::<DefaultDateMapping<MyFormat>>("20150703T145502Z").unwrap();
+        let third = parse::<DefaultDateMapping<MyFormat>>("1435935302478").unwrap();
+
+        assert_eq!(first, third);
+        assert_eq!((2u32, 0u32), (second.second(), second.nanosecond()));
+
+        assert_eq!("20150703T145502.478Z", format(&third).to_string());
+    }
+
+    #[test]
+    fn multi_format_parse_err() {
+        let err = parse::<DefaultDateMapping<MultiFormat<(BasicDateTime, EpochMillis)>>>("2015-07-03").unwrap_err();
+
+        assert_eq!(Some("2015-07-03"), err.input());
+    }
+
     #[test]
     fn custom_format() {
         #[derive(Default)]
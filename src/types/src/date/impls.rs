@@ -12,8 +12,15 @@ use super::mapping::{
     DefaultDateMapping,
 };
 use chrono::{
+    self,
     DateTime,
+    Duration,
+    FixedOffset,
+    NaiveDate,
+    NaiveDateTime,
+    NaiveTime,
     Utc,
+    Weekday,
 };
 use private::field::StdField;
 use serde::de::{
@@ -27,13 +34,22 @@ use serde::{
     Serializer,
 };
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::fmt::{
     Display,
     Formatter,
     Result as FmtResult,
 };
+use std::hash::{
+    Hash,
+    Hasher,
+};
 use std::marker::PhantomData;
-use std::ops::Deref;
+use std::ops::{
+    Add,
+    Deref,
+    Sub,
+};
 
 pub use chrono::{
     Datelike,
@@ -95,7 +111,7 @@ println!("{}/{}/{} {}:{}:{}.{}",
 
 - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/date.html)
 */
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Date<TMapping>
 where
     TMapping: DateMapping,
@@ -174,6 +190,11 @@ where
     # use elastic_types::prelude::*;
     let date: Date<DefaultDateMapping> = Date::build(2015, 5, 14, 16, 45, 8, 886);
     ```
+
+    # Panics
+
+    This method will panic if any of the primitives are out of range, like a month of `13`.
+    Use [`build_checked`](#method.build_checked) when the primitives come from user input.
     */
     pub fn build(
         year: i32,
@@ -189,6 +210,238 @@ where
         ))
     }
 
+    /**
+    Creates an `Date` from the given Utc primitives, returning an error if any of them are out of range.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Result<Date<DefaultDateMapping>, _> = Date::build_checked(2015, 13, 40, 16, 45, 8, 886);
+
+    assert!(date.is_err());
+    ```
+    */
+    pub fn build_checked(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        milli: u32,
+    ) -> Result<Self, ParseError> {
+        let date = DateValue::build_checked(year, month, day, hour, minute, second, milli)?;
+
+        Ok(Date::new(date))
+    }
+
+    /**
+    Creates a `Date` from the given `chrono::NaiveDate` at midnight UTC.
+
+    If the `Date`s format doesn't include a time, like `yyyy-MM-dd`, then only the date portion is formatted.
+
+    # Examples
+
+    ```
+    # extern crate elastic_types;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    use chrono::NaiveDate;
+
+    let date: Date<DefaultDateMapping> = Date::from_naive_date(NaiveDate::from_ymd(2015, 5, 14));
+
+    assert_eq!((2015, 5, 14, 0, 0, 0), (date.year(), date.month(), date.day(), date.hour(), date.minute(), date.second()));
+    # }
+    ```
+    */
+    pub fn from_naive_date(date: NaiveDate) -> Self {
+        let date = NaiveDateTime::new(date, NaiveTime::from_hms(0, 0, 0));
+
+        Date::new(DateValue::from(ChronoDateTime::from_utc(date, Utc)))
+    }
+
+    /**
+    Gets the date portion of this `Date` in UTC, dropping the time.
+
+    # Examples
+
+    ```
+    # extern crate elastic_types;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    use chrono::NaiveDate;
+
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 14, 16, 45, 8, 886);
+
+    assert_eq!(NaiveDate::from_ymd(2015, 5, 14), date.to_naive_date());
+    # }
+    ```
+    */
+    pub fn to_naive_date(&self) -> NaiveDate {
+        self.naive_utc().date()
+    }
+
+    /**
+    Borrow the inner `chrono::DateTime<Utc>`.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 14, 16, 45, 8, 886);
+
+    let chrono_date = date.as_chrono();
+    ```
+    */
+    pub fn as_chrono(&self) -> &ChronoDateTime {
+        self.value.borrow()
+    }
+
+    /**
+    Convert this `Date` into its inner `chrono::DateTime<Utc>`, dropping the format.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 14, 16, 45, 8, 886);
+
+    let chrono_date = date.into_chrono();
+    ```
+    */
+    pub fn into_chrono(self) -> ChronoDateTime {
+        *self.value.borrow()
+    }
+
+    /**
+    Format this `Date` as a string using the given format instead of the one in its mapping.
+
+    The date itself isn't changed, so it will still be serialised using the format in its mapping.
+
+    # Examples
+
+    Log a date that's stored as epoch millis in a readable form:
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 14, 16, 45, 8, 886);
+
+    assert_eq!("20150514T164508.886Z", date.format_as::<BasicDateTime>());
+    ```
+    */
+    pub fn format_as<TFormat>(&self) -> String
+    where
+        TFormat: DateFormat,
+    {
+        let value = DateValue::from(*self.value.borrow());
+
+        TFormat::format(&value).to_string()
+    }
+
+    /**
+    Gets the ISO 8601 week number, starting from `1`.
+
+    Days near the start or end of a year can belong to a week of the previous or next year.
+    Use `as_chrono().iso_week()` to get the year the week belongs to as well.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+
+    assert_eq!(20, date.iso_week());
+    ```
+    */
+    pub fn iso_week(&self) -> u32 {
+        Datelike::iso_week(self.as_chrono()).week()
+    }
+
+    /**
+    Gets the day of the week.
+
+    # Examples
+
+    ```
+    # extern crate elastic_types;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    use chrono::Weekday;
+
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+
+    assert_eq!(Weekday::Wed, date.weekday());
+    # }
+    ```
+    */
+    pub fn weekday(&self) -> Weekday {
+        Datelike::weekday(self.as_chrono())
+    }
+
+    /**
+    Gets the day of the year, starting from `1`.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+
+    assert_eq!(133, date.ordinal());
+    ```
+    */
+    pub fn ordinal(&self) -> u32 {
+        Datelike::ordinal(self.as_chrono())
+    }
+
+    /**
+    Truncate this date to midnight at the start of its day.
+
+    # Examples
+
+    Get the name of a daily index for a date:
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 14, 55, 43, 778);
+
+    let day = date.truncate_to_day();
+
+    assert_eq!((13, 0, 0, 0, 0), (day.day(), day.hour(), day.minute(), day.second(), day.nanosecond()));
+    assert_eq!("logs-2015.05.13", day.format("logs-%Y.%m.%d").to_string());
+    ```
+    */
+    pub fn truncate_to_day(self) -> Date<TMapping> {
+        let date = self.into_chrono().date().and_hms(0, 0, 0);
+
+        Date::new(DateValue::from(date))
+    }
+
+    /**
+    Truncate this date to the start of its hour.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 14, 55, 43, 778);
+
+    let hour = date.truncate_to_hour();
+
+    assert_eq!((14, 0, 0, 0), (hour.hour(), hour.minute(), hour.second(), hour.nanosecond()));
+    ```
+    */
+    pub fn truncate_to_hour(self) -> Date<TMapping> {
+        let date = self.into_chrono();
+        let date = date.date().and_hms(date.hour(), 0, 0);
+
+        Date::new(DateValue::from(date))
+    }
+
     /**
     Gets the current system time.
 
@@ -203,6 +456,182 @@ where
         Date::new(DateValue::now())
     }
 
+    /**
+    The earliest date that can be represented.
+
+    This is midnight on chrono's `MIN_DATE` in UTC, so the exact value follows chrono's limits.
+    Not every format can represent it, like formats with a 4 digit year.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping> = Date::min();
+    ```
+    */
+    pub fn min() -> Self {
+        Date::new(DateValue::from(chrono::MIN_DATE.and_hms(0, 0, 0)))
+    }
+
+    /**
+    The latest date that can be represented.
+
+    This is the last nanosecond of chrono's `MAX_DATE` in UTC, so the exact value follows chrono's limits.
+    Not every format can represent it, like formats with a 4 digit year.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let date: Date<DefaultDateMapping> = Date::max();
+    ```
+    */
+    pub fn max() -> Self {
+        Date::new(DateValue::from(
+            chrono::MAX_DATE.and_hms_nano(23, 59, 59, 999_999_999),
+        ))
+    }
+
+    /**
+    Parse a date using its format, assuming the given time if the input only contains a date.
+
+    Formats without a time component, like `yyyy-MM-dd`, assume midnight when they're deserialised.
+    That can shift a date onto the wrong day when it's later converted to another timezone,
+    so this method lets you choose the assumed time explicitly.
+    Formats that always include a time ignore the given time.
+
+    # Examples
+
+    Parse a date as the end of the day instead of midnight:
+
+    ```
+    # #[macro_use]
+    # extern crate elastic_types;
+    # #[macro_use]
+    # extern crate elastic_types_derive;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    use chrono::NaiveTime;
+
+    #[derive(Default, ElasticDateFormat)]
+    #[elastic(date_format="yyyy-MM-dd")]
+    struct MyFormat;
+
+    let date: Date<DefaultDateMapping<MyFormat>> = Date::parse_with_default_time("2015-05-13", NaiveTime::from_hms(23, 59, 59)).unwrap();
+
+    assert_eq!((13, 23, 59, 59), (date.day(), date.hour(), date.minute(), date.second()));
+    # }
+    ```
+    */
+    pub fn parse_with_default_time(date: &str, time: NaiveTime) -> Result<Self, ParseError> {
+        let parsed = FormattableDateValue::parse_with_default_time(date, time)?;
+
+        Ok(Date::new(parsed))
+    }
+
+    /**
+    Parse a date using its format, tolerating a few common deviations from it.
+
+    Surrounding whitespace is trimmed.
+    If the input still doesn't parse, it's retried with a space between the date and time replaced by `T`,
+    with a trailing `Z` removed, and with both.
+    If none of those parse, the error from parsing the trimmed input is returned.
+
+    # Examples
+
+    ```
+    # #[macro_use]
+    # extern crate elastic_types;
+    # #[macro_use]
+    # extern crate elastic_types_derive;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    #[derive(Default, ElasticDateFormat)]
+    #[elastic(date_format="yyyy-MM-dd'T'HH:mm:ss")]
+    struct MyFormat;
+
+    let date: Date<DefaultDateMapping<MyFormat>> = Date::parse_lenient(" 2015-05-13 10:00:00Z ").unwrap();
+
+    assert_eq!((13, 10), (date.day(), date.hour()));
+    # }
+    ```
+    */
+    pub fn parse_lenient(date: &str) -> Result<Self, ParseError> {
+        let date = date.trim();
+
+        let err = match parse(date) {
+            Ok(parsed) => return Ok(parsed),
+            Err(err) => err,
+        };
+
+        let with_t = date.replacen(' ', "T", 1);
+        let candidates = [
+            with_t.clone(),
+            date.trim_right_matches('Z').to_owned(),
+            with_t.trim_right_matches('Z').to_owned(),
+        ];
+
+        candidates
+            .iter()
+            .filter(|candidate| candidate.as_str() != date)
+            .filter_map(|candidate| parse(candidate).ok())
+            .next()
+            .ok_or(err)
+    }
+
+    /**
+    Parse an ISO8601 date with a timezone offset, like `2015-05-13T10:00:00+10:00`, keeping the offset.
+
+    A `Date` is always stored in UTC, so the offset is returned alongside it.
+    Pass the offset to [`format_with_offset`](#method.format_with_offset) to get the original date back.
+
+    # Examples
+
+    ```
+    # extern crate elastic_types;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    let (date, offset) = Date::<DefaultDateMapping>::parse_with_tz("2015-05-13T10:00:00+10:00").unwrap();
+
+    assert_eq!((13, 0), (date.day(), date.hour()));
+    assert_eq!("2015-05-13T10:00:00+10:00", date.format_with_offset(offset));
+    # }
+    ```
+    */
+    pub fn parse_with_tz(date: &str) -> Result<(Self, FixedOffset), ParseError> {
+        let parsed = DateTime::parse_from_rfc3339(date)?;
+        let offset = *parsed.offset();
+
+        Ok((Date::new(DateValue::from(parsed.with_timezone(&Utc))), offset))
+    }
+
+    /**
+    Format this date as an ISO8601 string in the given timezone offset.
+
+    This ignores the format of the `Date`.
+
+    # Examples
+
+    ```
+    # extern crate elastic_types;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    use chrono::FixedOffset;
+
+    let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+
+    assert_eq!("2015-05-12T19:00:00-05:00", date.format_with_offset(FixedOffset::west(5 * 3600)));
+    # }
+    ```
+    */
+    pub fn format_with_offset(&self, offset: FixedOffset) -> String {
+        self.with_timezone(&offset).to_rfc3339()
+    }
+
     /**
     Change the format/mapping of this date.
 
@@ -223,6 +652,107 @@ where
     {
         Date::new(DateValue::from(date.value))
     }
+
+    /**
+    Check whether this date is within the given tolerance of another date.
+
+    The dates are compared as instants, so they can have different formats.
+    This is useful when a date has been through a format with a lower precision, like `epoch_second`.
+
+    # Examples
+
+    ```
+    # extern crate elastic_types;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    use chrono::Duration;
+
+    let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 14, 16, 45, 8, 886);
+    let truncated: Date<DefaultDateMapping<BasicDateTimeNoMillis>> = Date::build(2015, 5, 14, 16, 45, 8, 0);
+
+    assert!(date.approx_eq(&truncated, Duration::seconds(1)));
+    assert!(!date.approx_eq(&truncated, Duration::milliseconds(10)));
+    # }
+    ```
+    */
+    pub fn approx_eq<TOtherMapping>(&self, other: &Date<TOtherMapping>, tolerance: Duration) -> bool
+    where
+        TOtherMapping: DateMapping,
+    {
+        let diff = self.signed_duration_since(**other);
+
+        diff <= tolerance && -diff <= tolerance
+    }
+
+    /**
+    Get the instant halfway between this date and another date.
+
+    The dates are compared as instants, so they can have different formats.
+    The result keeps the format of this date.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let start: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+    let end: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 14, 0, 0, 0, 0);
+
+    let mid = start.midpoint(&end);
+
+    assert_eq!((13, 12), (mid.day(), mid.hour()));
+    ```
+    */
+    pub fn midpoint<TOtherMapping>(&self, other: &Date<TOtherMapping>) -> Date<TMapping>
+    where
+        TOtherMapping: DateMapping,
+    {
+        // The difference between any two dates fits in a `Duration`, so halving it can't overflow
+        let half = other.signed_duration_since(**self) / 2;
+
+        Date::new(DateValue::from(**self + half))
+    }
+
+    /**
+    Get the instant at a fraction `t` of the way from this date to another date.
+
+    A `t` of `0.0` is this date and a `t` of `1.0` is the other date.
+    Values outside that range are clamped, so the result is always between the two dates.
+    The result is accurate to the millisecond and keeps the format of this date.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let start: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+    let end: Date<DefaultDateMapping> = Date::build(2015, 5, 14, 0, 0, 0, 0);
+
+    let date = start.lerp(&end, 0.25);
+
+    assert_eq!((13, 6), (date.day(), date.hour()));
+    ```
+    */
+    pub fn lerp<TOtherMapping>(&self, other: &Date<TOtherMapping>, t: f64) -> Date<TMapping>
+    where
+        TOtherMapping: DateMapping,
+    {
+        let t = t.max(0.0).min(1.0);
+
+        if t == 1.0 {
+            return Date::new(DateValue::from(**other));
+        }
+
+        // Spans above 2^53 milliseconds lose precision as a `f64`, so rounding can overshoot the span.
+        // Clamping the offset to the span keeps the result between the two dates
+        let diff = other.signed_duration_since(**self).num_milliseconds();
+        let offset = (diff as f64 * t).round() as i64;
+        let offset = if diff < 0 { offset.max(diff) } else { offset.min(diff) };
+
+        match self.checked_add_signed(Duration::milliseconds(offset)) {
+            Some(date) => Date::new(DateValue::from(date)),
+            None => Date::new(DateValue::from(**other)),
+        }
+    }
 }
 
 impl<TMapping> DateFieldType<TMapping> for Date<TMapping> where TMapping: DateMapping {}
@@ -263,6 +793,24 @@ where
     }
 }
 
+impl<TMapping> From<ChronoDateTime> for Date<TMapping>
+where
+    TMapping: DateMapping,
+{
+    fn from(value: ChronoDateTime) -> Self {
+        Date::new(DateValue::from(value))
+    }
+}
+
+impl<TMapping> From<Date<TMapping>> for ChronoDateTime
+where
+    TMapping: DateMapping,
+{
+    fn from(date: Date<TMapping>) -> Self {
+        date.into_chrono()
+    }
+}
+
 impl<TMapping> StdField<ChronoDateTime> for Date<TMapping> where TMapping: DateMapping {}
 
 impl<TMapping> PartialEq<ChronoDateTime> for Date<TMapping>
@@ -291,6 +839,83 @@ where
     }
 }
 
+impl<TMapping, TOtherMapping> PartialEq<Date<TOtherMapping>> for Date<TMapping>
+where
+    TMapping: DateMapping,
+    TOtherMapping: DateMapping,
+{
+    fn eq(&self, other: &Date<TOtherMapping>) -> bool {
+        PartialEq::eq(&**self, &**other)
+    }
+}
+
+impl<TMapping> Eq for Date<TMapping> where TMapping: DateMapping {}
+
+impl<TMapping, TOtherMapping> PartialOrd<Date<TOtherMapping>> for Date<TMapping>
+where
+    TMapping: DateMapping,
+    TOtherMapping: DateMapping,
+{
+    fn partial_cmp(&self, other: &Date<TOtherMapping>) -> Option<Ordering> {
+        Some(Ord::cmp(&**self, &**other))
+    }
+}
+
+impl<TMapping> Ord for Date<TMapping>
+where
+    TMapping: DateMapping,
+{
+    fn cmp(&self, other: &Date<TMapping>) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<TMapping> Hash for Date<TMapping>
+where
+    TMapping: DateMapping,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).hash(state)
+    }
+}
+
+impl<TMapping> Add<Duration> for Date<TMapping>
+where
+    TMapping: DateMapping,
+{
+    type Output = Date<TMapping>;
+
+    fn add(self, rhs: Duration) -> Date<TMapping> {
+        Date::new(DateValue::from(self.into_chrono() + rhs))
+    }
+}
+
+impl<TMapping> Sub<Duration> for Date<TMapping>
+where
+    TMapping: DateMapping,
+{
+    type Output = Date<TMapping>;
+
+    fn sub(self, rhs: Duration) -> Date<TMapping> {
+        Date::new(DateValue::from(self.into_chrono() - rhs))
+    }
+}
+
+impl<TMapping, TOtherMapping> Sub<Date<TOtherMapping>> for Date<TMapping>
+where
+    TMapping: DateMapping,
+    TOtherMapping: DateMapping,
+{
+    type Output = Duration;
+
+    fn sub(self, rhs: Date<TOtherMapping>) -> Duration {
+        self.signed_duration_since(rhs.into_chrono())
+    }
+}
+
 impl<TMapping> Deref for Date<TMapping>
 where
     TMapping: DateMapping,
@@ -336,7 +961,7 @@ where
     where
         S: Serializer,
     {
-        serializer.collect_str(&self)
+        format(self).serialize(serializer)
     }
 }
 
@@ -647,23 +1272,110 @@ where
         }
     }
 
-    impl_expr_ops!(DateExprOpUnit::Year, add_years, sub_years, round_year);
-    impl_expr_ops!(DateExprOpUnit::Month, add_months, sub_months, round_month);
-    impl_expr_ops!(DateExprOpUnit::Week, add_weeks, sub_weeks, round_week);
-    impl_expr_ops!(DateExprOpUnit::Day, add_days, sub_days, round_day);
-    impl_expr_ops!(DateExprOpUnit::Hour, add_hours, sub_hours, round_hour);
-    impl_expr_ops!(
-        DateExprOpUnit::Minute,
-        add_minutes,
-        sub_minutes,
-        round_minute
-    );
-    impl_expr_ops!(
-        DateExprOpUnit::Second,
-        add_seconds,
-        sub_seconds,
-        round_second
-    );
+    impl_expr_ops!(DateExprOpUnit::Year, add_years, sub_years, round_year);
+    impl_expr_ops!(DateExprOpUnit::Month, add_months, sub_months, round_month);
+    impl_expr_ops!(DateExprOpUnit::Week, add_weeks, sub_weeks, round_week);
+    impl_expr_ops!(DateExprOpUnit::Day, add_days, sub_days, round_day);
+    impl_expr_ops!(DateExprOpUnit::Hour, add_hours, sub_hours, round_hour);
+    impl_expr_ops!(
+        DateExprOpUnit::Minute,
+        add_minutes,
+        sub_minutes,
+        round_minute
+    );
+    impl_expr_ops!(
+        DateExprOpUnit::Second,
+        add_seconds,
+        sub_seconds,
+        round_second
+    );
+
+    /**
+    Parse a date math expression, like `now-1d/d` or `2015-05-13||+1M/M`.
+
+    Any anchor date is parsed using the format of the `DateExpr`.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let expr: DateExpr<BasicDateTime> = DateExpr::parse("now-1d/d").unwrap();
+
+    assert_eq!(DateExpr::now().sub_days(1).round_day(), expr);
+    ```
+    */
+    pub fn parse(expr: &str) -> Result<Self, ParseError> {
+        let (anchor, ops) = if expr.starts_with("now") {
+            (DateExprAnchor::Now, &expr[3..])
+        } else {
+            let (date, ops) = match expr.find("||") {
+                Some(i) => (&expr[..i], &expr[i + 2..]),
+                None => (expr, ""),
+            };
+
+            (DateExprAnchor::Value(FormattableDateValue::parse(date)?), ops)
+        };
+
+        Ok(DateExpr {
+            anchor: anchor,
+            ops: DateExprOp::parse_all(ops)?,
+        })
+    }
+}
+
+impl DateExprOp {
+    fn parse_all(ops: &str) -> Result<Vec<DateExprOp>, ParseError> {
+        let mut parsed = Vec::new();
+        let mut chars = ops.chars().peekable();
+
+        while let Some(op) = chars.next() {
+            let op = match op {
+                '+' | '-' => {
+                    let mut size = String::new();
+                    while let Some(&c) = chars.peek() {
+                        if !c.is_ascii_digit() {
+                            break;
+                        }
+
+                        size.push(c);
+                        chars.next();
+                    }
+
+                    let size = size
+                        .parse()
+                        .map_err(|_| format!("expected a number after '{}' in '{}'", op, ops))?;
+                    let unit = DateExprOpUnit::parse(chars.next(), ops)?;
+
+                    if op == '+' {
+                        DateExprOp::Add(size, unit)
+                    } else {
+                        DateExprOp::Sub(size, unit)
+                    }
+                }
+                '/' => DateExprOp::Round(DateExprOpUnit::parse(chars.next(), ops)?),
+                c => return Err(format!("unexpected '{}' in date math '{}'", c, ops).into()),
+            };
+
+            parsed.push(op);
+        }
+
+        Ok(parsed)
+    }
+}
+
+impl DateExprOpUnit {
+    fn parse(unit: Option<char>, ops: &str) -> Result<DateExprOpUnit, ParseError> {
+        match unit {
+            Some('y') => Ok(DateExprOpUnit::Year),
+            Some('M') => Ok(DateExprOpUnit::Month),
+            Some('w') => Ok(DateExprOpUnit::Week),
+            Some('d') => Ok(DateExprOpUnit::Day),
+            Some('h') | Some('H') => Ok(DateExprOpUnit::Hour),
+            Some('m') => Ok(DateExprOpUnit::Minute),
+            Some('s') => Ok(DateExprOpUnit::Second),
+            _ => Err(format!("expected a time unit in date math '{}'", ops).into()),
+        }
+    }
 }
 
 impl<TFormat> Serialize for DateExpr<TFormat>
@@ -678,6 +1390,40 @@ where
     }
 }
 
+impl<'de, TFormat> Deserialize<'de> for DateExpr<TFormat>
+where
+    TFormat: DateFormat,
+{
+    fn deserialize<D>(deserializer: D) -> Result<DateExpr<TFormat>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DateExprVisitor<TFormat> {
+            _f: PhantomData<TFormat>,
+        }
+
+        impl<'de, TFormat> Visitor<'de> for DateExprVisitor<TFormat>
+        where
+            TFormat: DateFormat,
+        {
+            type Value = DateExpr<TFormat>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(formatter, "a date math expression")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<DateExpr<TFormat>, E>
+            where
+                E: Error,
+            {
+                DateExpr::parse(v).map_err(|err| Error::custom(format!("{}", err)))
+            }
+        }
+
+        deserializer.deserialize_str(DateExprVisitor::<TFormat> { _f: PhantomData })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono;
@@ -694,6 +1440,87 @@ mod tests {
     #[elastic(date_format = "yyyyMMdd")]
     pub struct UnNamedDateFormat;
 
+    #[derive(ElasticDateFormat, Default, Clone)]
+    #[elastic(date_format = "yyyy-MM-dd'T'HH:mm:ss")]
+    pub struct IsoDateFormat;
+
+    #[test]
+    fn parse_date_only_assumes_midnight() {
+        let date = parse::<DefaultDateMapping<UnNamedDateFormat>>("20150513").unwrap();
+
+        assert_eq!(
+            (2015, 5, 13, 0, 0, 0),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_date_only_with_default_time() {
+        let date = Date::<DefaultDateMapping<UnNamedDateFormat>>::parse_with_default_time(
+            "20150513",
+            chrono::NaiveTime::from_hms_milli(12, 30, 15, 500),
+        ).unwrap();
+
+        assert_eq!(
+            (2015, 5, 13, 12, 30, 15, 500),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second(),
+                date.nanosecond() / 1000000
+            )
+        );
+    }
+
+    #[test]
+    fn parse_date_time_ignores_default_time() {
+        let date = Date::<DefaultDateMapping<NamedDateFormat>>::parse_with_default_time(
+            "2015/05/13 14:55:02",
+            chrono::NaiveTime::from_hms(23, 59, 59),
+        ).unwrap();
+
+        assert_eq!(
+            (14, 55, 2),
+            (date.hour(), date.minute(), date.second())
+        );
+    }
+
+    #[test]
+    fn from_naive_date_is_midnight_utc() {
+        let date = Date::<DefaultDateMapping<NamedDateFormat>>::from_naive_date(
+            chrono::NaiveDate::from_ymd(2015, 5, 13),
+        );
+
+        assert_eq!("2015/05/13 00:00:00", format(&date).to_string());
+        assert_eq!(chrono::NaiveDate::from_ymd(2015, 5, 13), date.to_naive_date());
+    }
+
+    #[test]
+    fn from_naive_date_formats_date_only() {
+        let date = Date::<DefaultDateMapping<UnNamedDateFormat>>::from_naive_date(
+            chrono::NaiveDate::from_ymd(2015, 5, 13),
+        );
+
+        assert_eq!("20150513", format(&date).to_string());
+    }
+
+    #[test]
+    fn to_naive_date_drops_time() {
+        let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 23, 59, 59, 999);
+
+        assert_eq!(chrono::NaiveDate::from_ymd(2015, 5, 13), date.to_naive_date());
+    }
+
     #[test]
     fn date_format_uses_name_if_supplied() {
         assert_eq!("test_date_1", NamedDateFormat::name());
@@ -735,6 +1562,296 @@ mod tests {
         assert!(takes_epoch_millis(Date::remap(date)));
     }
 
+    #[test]
+    fn dates_approx_eq_within_tolerance() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+        let other: Date<DefaultDateMapping<BasicDateTimeNoMillis>> = Date::build(2015, 5, 13, 0, 0, 1, 0);
+
+        assert!(date.approx_eq(&other, chrono::Duration::seconds(1)));
+        assert!(other.approx_eq(&date, chrono::Duration::seconds(1)));
+        assert!(date.approx_eq(&other, chrono::Duration::milliseconds(500)));
+    }
+
+    #[test]
+    fn dates_not_approx_eq_outside_tolerance() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+        let other: Date<DefaultDateMapping<BasicDateTimeNoMillis>> = Date::build(2015, 5, 13, 0, 0, 1, 0);
+
+        assert!(!date.approx_eq(&other, chrono::Duration::milliseconds(499)));
+        assert!(!other.approx_eq(&date, chrono::Duration::milliseconds(499)));
+    }
+
+    #[test]
+    fn date_midpoint() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+        let other: Date<DefaultDateMapping<BasicDateTimeNoMillis>> = Date::build(2015, 5, 13, 0, 0, 1, 0);
+
+        let expected: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+
+        assert_eq!(*expected, *date.midpoint(&other));
+        assert_eq!(*expected, *other.midpoint(&date));
+    }
+
+    #[test]
+    fn date_midpoint_far_apart() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(-200000, 1, 1, 0, 0, 0, 0);
+        let other: Date<DefaultDateMapping<EpochMillis>> = Date::build(200000, 1, 1, 0, 0, 0, 0);
+
+        let expected: Date<DefaultDateMapping<EpochMillis>> = Date::build(0, 1, 1, 0, 0, 0, 0);
+
+        assert_eq!(*expected, *date.midpoint(&other));
+    }
+
+    #[test]
+    fn date_lerp() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+        let other: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 1, 0);
+
+        let quarter: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 250);
+        let three_quarters: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 750);
+
+        assert_eq!(*date, *date.lerp(&other, 0.0));
+        assert_eq!(*other, *date.lerp(&other, 1.0));
+        assert_eq!(*quarter, *date.lerp(&other, 0.25));
+        assert_eq!(*three_quarters, *other.lerp(&date, 0.25));
+    }
+
+    #[test]
+    fn date_lerp_clamps() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+        let other: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 1, 0);
+
+        assert_eq!(*date, *date.lerp(&other, -1.0));
+        assert_eq!(*other, *date.lerp(&other, 2.0));
+    }
+
+    #[test]
+    fn date_lerp_min_and_max() {
+        let min: Date<DefaultDateMapping> = Date::min();
+        let max: Date<DefaultDateMapping> = Date::max();
+
+        assert_eq!(*max, *min.lerp(&max, 1.0));
+        assert_eq!(*min, *max.lerp(&min, 1.0));
+
+        assert!(*min.lerp(&max, 0.999_999_999_999) <= *max);
+        assert!(*max.lerp(&min, 0.999_999_999_999) >= *min);
+    }
+
+    #[test]
+    fn dates_with_different_formats_are_eq() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+        let other: Date<DefaultDateMapping<BasicDateTime>> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+
+        assert!(date == other);
+        assert_eq!(Some(::std::cmp::Ordering::Equal), date.partial_cmp(&other));
+    }
+
+    #[test]
+    fn dates_are_ordered() {
+        let earlier: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+        let later: Date<DefaultDateMapping<BasicDateTimeNoMillis>> = Date::build(2015, 5, 13, 0, 0, 1, 0);
+
+        assert!(earlier < later);
+        assert!(later > earlier);
+    }
+
+    #[test]
+    fn sort_dates_after_remap() {
+        let a: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 15, 0, 0, 0, 0);
+        let b: Date<DefaultDateMapping<BasicDateTimeNoMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+        let c: Date<DefaultDateMapping<ChronoFormat>> = Date::build(2015, 5, 14, 0, 0, 0, 0);
+
+        let mut dates: Vec<Date<DefaultDateMapping<BasicDateTime>>> =
+            vec![Date::remap(a), Date::remap(b), Date::remap(c)];
+        dates.sort();
+
+        let days: Vec<u32> = dates.iter().map(|date| date.day()).collect();
+
+        assert_eq!(vec![13, 14, 15], days);
+    }
+
+    #[test]
+    fn parse_lenient_date() {
+        let expected: Date<DefaultDateMapping<IsoDateFormat>> = Date::build(2015, 5, 13, 10, 0, 0, 0);
+
+        for input in &[
+            "2015-05-13T10:00:00",
+            " 2015-05-13T10:00:00\n",
+            "2015-05-13 10:00:00",
+            "2015-05-13T10:00:00Z",
+            " 2015-05-13 10:00:00Z ",
+        ] {
+            let date: Date<DefaultDateMapping<IsoDateFormat>> = Date::parse_lenient(input).unwrap();
+
+            assert_eq!(expected, date);
+        }
+
+        assert!(Date::<DefaultDateMapping<IsoDateFormat>>::parse_lenient("13/05/2015").is_err());
+    }
+
+    #[test]
+    fn min_and_max_dates() {
+        let min: Date<DefaultDateMapping> = Date::min();
+        let max: Date<DefaultDateMapping> = Date::max();
+
+        assert_eq!(chrono::MIN_DATE.and_hms(0, 0, 0), *min);
+        assert_eq!(
+            chrono::MAX_DATE.and_hms_nano(23, 59, 59, 999_999_999),
+            *max
+        );
+        assert!(min < max);
+    }
+
+    #[test]
+    fn hash_equal_dates_from_different_formats() {
+        use std::collections::HashSet;
+
+        let a: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+        let b: Date<DefaultDateMapping<BasicDateTime>> =
+            serde_json::from_str(r#""20150513T000000.500Z""#).unwrap();
+
+        let mut dates: HashSet<Date<DefaultDateMapping<BasicDateTime>>> = HashSet::new();
+        dates.insert(Date::remap(a));
+        dates.insert(Date::remap(b));
+
+        assert_eq!(1, dates.len());
+    }
+
+    #[test]
+    fn date_format_as() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+
+        assert_eq!("2015-05-13T00:00:00Z", date.format_as::<ChronoFormat>());
+        assert_eq!("1431475200", date.format_as::<EpochSecond>());
+        assert_eq!("1431475200500", format(&date).to_string());
+    }
+
+    #[test]
+    fn date_from_chrono() {
+        let chrono_date = chrono::Utc.ymd(2015, 5, 13).and_hms_milli(0, 0, 0, 500);
+
+        let date: Date<DefaultDateMapping<EpochMillis>> = chrono_date.into();
+        assert_eq!(chrono_date, *date);
+
+        let roundtrip: ChronoDateTime = date.into();
+        assert_eq!(chrono_date, roundtrip);
+    }
+
+    #[test]
+    fn collect_dates_from_chrono() {
+        let dates: Vec<Date<DefaultDateMapping>> = (13..16)
+            .map(|day| chrono::Utc.ymd(2015, 5, day).and_hms(0, 0, 0))
+            .map(Into::into)
+            .collect();
+
+        let days: Vec<u32> = dates.iter().map(|date| date.day()).collect();
+
+        assert_eq!(vec![13, 14, 15], days);
+    }
+
+    #[test]
+    fn date_as_chrono() {
+        let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 500);
+
+        let expected = chrono::Utc.ymd(2015, 5, 13).and_hms_milli(0, 0, 0, 500);
+
+        assert_eq!(&expected, date.as_chrono());
+        assert_eq!(expected, date.into_chrono());
+    }
+
+    #[test]
+    fn add_duration_to_date() {
+        let date: Date<DefaultDateMapping<BasicDateTime>> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+
+        let date: Date<DefaultDateMapping<BasicDateTime>> = date + chrono::Duration::days(1);
+
+        assert_eq!((14, 0), (date.day(), date.hour()));
+    }
+
+    #[test]
+    fn sub_duration_from_date() {
+        let date: Date<DefaultDateMapping<BasicDateTime>> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+
+        let date: Date<DefaultDateMapping<BasicDateTime>> = date - chrono::Duration::hours(1);
+
+        assert_eq!((12, 23), (date.day(), date.hour()));
+    }
+
+    #[test]
+    fn sub_dates() {
+        let date: Date<DefaultDateMapping<BasicDateTime>> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+        let other: Date<DefaultDateMapping<EpochMillis>> = Date::build(2015, 5, 14, 0, 0, 0, 500);
+
+        assert_eq!(chrono::Duration::milliseconds(86400500), other.clone() - date.clone());
+        assert_eq!(chrono::Duration::milliseconds(-86400500), date - other);
+    }
+
+    #[test]
+    fn build_checked_date() {
+        let date: Date<DefaultDateMapping> = Date::build_checked(2015, 5, 13, 0, 0, 0, 500).unwrap();
+
+        assert_eq!(*Date::<DefaultDateMapping>::build(2015, 5, 13, 0, 0, 0, 500), *date);
+    }
+
+    #[test]
+    fn build_checked_date_out_of_range() {
+        assert!(Date::<DefaultDateMapping>::build_checked(2015, 13, 1, 0, 0, 0, 0).is_err());
+        assert!(Date::<DefaultDateMapping>::build_checked(2015, 2, 30, 0, 0, 0, 0).is_err());
+        assert!(Date::<DefaultDateMapping>::build_checked(2015, 5, 13, 24, 0, 0, 0).is_err());
+        assert!(Date::<DefaultDateMapping>::build_checked(2015, 5, 13, 0, 60, 0, 0).is_err());
+        assert!(Date::<DefaultDateMapping>::build_checked(2015, 5, 13, 0, 0, 0, 2000).is_err());
+    }
+
+    #[test]
+    fn parse_with_tz_keeps_offset() {
+        let (date, offset) =
+            Date::<DefaultDateMapping>::parse_with_tz("2015-05-13T10:00:00.500+10:00").unwrap();
+
+        assert_eq!(*Date::<DefaultDateMapping>::build(2015, 5, 13, 0, 0, 0, 500), *date);
+        assert_eq!(chrono::FixedOffset::east(10 * 3600), offset);
+        assert_eq!("2015-05-13T10:00:00.500+10:00", date.format_with_offset(offset));
+    }
+
+    #[test]
+    fn parse_with_tz_without_offset_is_err() {
+        assert!(Date::<DefaultDateMapping>::parse_with_tz("2015-05-13T10:00:00").is_err());
+    }
+
+    #[test]
+    fn date_week_accessors() {
+        let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+
+        assert_eq!(20, date.iso_week());
+        assert_eq!(chrono::Weekday::Wed, date.weekday());
+        assert_eq!(133, date.ordinal());
+    }
+
+    #[test]
+    fn date_iso_week_at_year_boundary() {
+        let date: Date<DefaultDateMapping> = Date::build(2016, 1, 1, 0, 0, 0, 0);
+
+        assert_eq!(53, date.iso_week());
+        assert_eq!(1, date.ordinal());
+    }
+
+    #[test]
+    fn truncate_date_to_day() {
+        let date: Date<DefaultDateMapping<BasicDateTime>> = Date::build(2015, 5, 13, 14, 55, 43, 778);
+
+        let day: Date<DefaultDateMapping<BasicDateTime>> = date.truncate_to_day();
+
+        assert_eq!(*Date::<DefaultDateMapping>::build(2015, 5, 13, 0, 0, 0, 0), *day);
+    }
+
+    #[test]
+    fn truncate_date_to_hour() {
+        let date: Date<DefaultDateMapping<BasicDateTime>> = Date::build(2015, 5, 13, 14, 55, 43, 778);
+
+        let hour: Date<DefaultDateMapping<BasicDateTime>> = date.truncate_to_hour();
+
+        assert_eq!(*Date::<DefaultDateMapping>::build(2015, 5, 13, 14, 0, 0, 0), *hour);
+    }
+
     #[test]
     fn can_build_date_from_value() {
         let date: Date<DefaultDateMapping> = Date::new(DateValue::build(2015, 05, 13, 0, 0, 0, 0));
@@ -801,6 +1918,22 @@ mod tests {
         assert_eq!(r#""20150513T000000.000Z""#, ser);
     }
 
+    #[test]
+    fn serialise_epoch_millis_date_as_number() {
+        let date = Date::<DefaultDateMapping<EpochMillis>>::build(2015, 5, 13, 12, 55, 8, 886);
+
+        let ser = serde_json::to_string(&date).unwrap();
+
+        assert_eq!("1431521708886", ser);
+    }
+
+    #[test]
+    fn deserialise_epoch_millis_date_from_number() {
+        let date: Date<DefaultDateMapping<EpochMillis>> = serde_json::from_str("1431521708886").unwrap();
+
+        assert_eq!(*Date::<DefaultDateMapping>::build(2015, 5, 13, 12, 55, 8, 886), *date);
+    }
+
     #[test]
     fn deserialise_elastic_date() {
         let date: Date<DefaultDateMapping<BasicDateTime>> =
@@ -887,6 +2020,56 @@ mod tests {
         assert_eq!(r#""now-1y-2M-3w-4d-5h-6m-7s""#, ser);
     }
 
+    #[test]
+    fn parse_date_expr_now() {
+        let expr: DateExpr<BasicDateTime> = DateExpr::parse("now-1d/d").unwrap();
+
+        assert_eq!(DateExpr::now().sub_days(1).round_day(), expr);
+        assert_eq!("now-1d/d", expr.to_string());
+    }
+
+    #[test]
+    fn parse_date_expr_value_with_ops() {
+        let expr: DateExpr<BasicDateTime> = DateExpr::parse("20150513T000000.000Z||+1M/M").unwrap();
+
+        let expected = DateExpr::value(DateValue::build(2015, 5, 13, 0, 0, 0, 0))
+            .add_months(1)
+            .round_month();
+
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn parse_date_expr_value_without_ops() {
+        let expr: DateExpr<BasicDateTime> = DateExpr::parse("20150513T000000.000Z").unwrap();
+
+        assert_eq!(r#""20150513T000000.000Z||""#, serde_json::to_string(&expr).unwrap());
+    }
+
+    #[test]
+    fn parse_date_expr_all_units() {
+        let expr = "now+1y-2M+3w-4d+5h-6m+7s/y/M/w/d/h/m/s";
+
+        let parsed: DateExpr<DefaultDateFormat> = DateExpr::parse(expr).unwrap();
+
+        assert_eq!(expr, parsed.to_string());
+    }
+
+    #[test]
+    fn parse_date_expr_invalid() {
+        assert!(DateExpr::<DefaultDateFormat>::parse("now+d").is_err());
+        assert!(DateExpr::<DefaultDateFormat>::parse("now+1q").is_err());
+        assert!(DateExpr::<DefaultDateFormat>::parse("now*1d").is_err());
+        assert!(DateExpr::<DefaultDateFormat>::parse("2015-05-13||+1d").is_err());
+    }
+
+    #[test]
+    fn deserialise_date_expr() {
+        let expr: DateExpr<DefaultDateFormat> = serde_json::from_str(r#""now+2d/w""#).unwrap();
+
+        assert_eq!(DateExpr::now().add_days(2).round_week(), expr);
+    }
+
     #[test]
     fn serialise_date_expr_round() {
         let expr = DateExpr::<DefaultDateFormat>::now()
@@ -134,7 +134,7 @@ pub trait DateMapping {
         None
     }
 
-    /** Should the field be searchable? Accepts `not_analyzed` (default) and `no`. */
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
     fn index() -> Option<bool> {
         None
     }
@@ -323,7 +323,7 @@ mod tests {
             "index": true,
             "store": true,
             "ignore_malformed": true,
-            "null_value": "1426351513778"
+            "null_value": 1426351513778
         });
 
         assert_eq!(expected, ser);
@@ -5,6 +5,7 @@ Dates in Elasticsearch are exposed as a formatted `string` which can contain a `
 
 All dates used by `elastic_types` are expected to be given in `Utc`, and if no time is supplied, then 12:00am will be used instead.
 Where performance is paramount, the `EpochMillis` date format will parse and format dates the fastest.
+Dates written by other tools using Elasticsearch's default format can be read with the `DateOptionalTime` format.
 
 # Date types
 
@@ -115,11 +116,35 @@ impl DateFormat for Rfc3339Format {
 # }
 ```
 
+## Runtime Formats
+
+Date formats are types rather than values, because a format's `name` is written into the index mapping, which is also built from types.
+So a format can't be chosen at runtime, like from a config file.
+Instead, parse the input with a `chrono` pattern and convert the result into a `Date` that uses a known format:
+
+```
+# extern crate elastic_types;
+# extern crate chrono;
+# use elastic_types::prelude::*;
+# fn main() {
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+let pattern = String::from("%d/%m/%Y %H:%M");
+
+let parsed = NaiveDateTime::parse_from_str("13/05/2015 00:00", &pattern).unwrap();
+let date: Date<DefaultDateMapping> = DateTime::<Utc>::from_utc(parsed, Utc).into();
+
+assert_eq!("20150513T000000.000Z", date.format_as::<BasicDateTime>());
+# }
+```
+
 # Links
 - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/date.html)
 */
 
 pub mod mapping;
+pub mod range;
+pub mod time;
 
 mod format;
 mod formats;
@@ -144,5 +169,7 @@ pub mod prelude {
     pub use super::formats::*;
     pub use super::impls::*;
     pub use super::mapping::*;
+    pub use super::range::prelude::*;
+    pub use super::time::prelude::*;
     pub use super::DefaultDateFormat;
 }
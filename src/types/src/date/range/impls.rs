@@ -0,0 +1,334 @@
+use super::mapping::{
+    DateRangeFieldType,
+    DateRangeMapping,
+};
+use date::{
+    Date,
+    DateFormat,
+    DefaultDateMapping,
+};
+use private::range::{
+    deserialize_bounds,
+    is_bounded,
+};
+use serde::de::{
+    MapAccess,
+    Visitor,
+};
+use serde::ser::SerializeMap;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::fmt::{
+    Formatter,
+    Result as FmtResult,
+};
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+/** A bound of a `DateRange`, using the range mapping's format for its date. */
+pub type DateRangeBound<TMapping> =
+    Bound<Date<DefaultDateMapping<<TMapping as DateRangeMapping>::Format>>>;
+
+/**
+An Elasticsearch `date_range` type with optional lower and upper bounds.
+
+Each bound is a `std::ops::Bound`, so it can be inclusive, exclusive or open.
+The [format](../trait.DateFormat.html) of the bounds is provided by the mapping.
+
+# Examples
+
+Defining a range that covers the first half of 2015:
+
+```
+# use elastic_types::prelude::*;
+use std::ops::Bound;
+
+let range: DateRange<DefaultDateRangeMapping> = DateRange::new(
+    Bound::Included(Date::build(2015, 1, 1, 0, 0, 0, 0)),
+    Bound::Excluded(Date::build(2015, 7, 1, 0, 0, 0, 0)),
+);
+```
+
+Defining a range with no upper bound:
+
+```
+# use elastic_types::prelude::*;
+use std::ops::Bound;
+
+let range: DateRange<DefaultDateRangeMapping> = DateRange::new(
+    Bound::Included(Date::build(2015, 1, 1, 0, 0, 0, 0)),
+    Bound::Unbounded,
+);
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/range.html)
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateRange<TMapping>
+where
+    TMapping: DateRangeMapping,
+{
+    lower: Bound<Date<DefaultDateMapping<TMapping::Format>>>,
+    upper: Bound<Date<DefaultDateMapping<TMapping::Format>>>,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> DateRange<TMapping>
+where
+    TMapping: DateRangeMapping,
+{
+    /**
+    Creates a new `DateRange` from the given lower and upper bounds.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    use std::ops::Bound;
+
+    let range: DateRange<DefaultDateRangeMapping> = DateRange::new(
+        Bound::Unbounded,
+        Bound::Included(Date::build(2015, 7, 1, 0, 0, 0, 0)),
+    );
+    ```
+    */
+    pub fn new(lower: DateRangeBound<TMapping>, upper: DateRangeBound<TMapping>) -> Self {
+        DateRange {
+            lower: lower,
+            upper: upper,
+            _m: PhantomData,
+        }
+    }
+
+    /** The lower bound of the range, serialised as `gte` or `gt`. */
+    pub fn lower(&self) -> &DateRangeBound<TMapping> {
+        &self.lower
+    }
+
+    /** The upper bound of the range, serialised as `lte` or `lt`. */
+    pub fn upper(&self) -> &DateRangeBound<TMapping> {
+        &self.upper
+    }
+
+    /**
+    Change the mapping of this range.
+
+    The bounds are kept as-is, but will be formatted using the new mapping.
+    */
+    pub fn remap<TNewMapping>(range: DateRange<TMapping>) -> DateRange<TNewMapping>
+    where
+        TNewMapping: DateRangeMapping,
+    {
+        DateRange::new(remap_bound(range.lower), remap_bound(range.upper))
+    }
+}
+
+fn remap_bound<TMapping, TNewMapping>(
+    bound: DateRangeBound<TMapping>,
+) -> DateRangeBound<TNewMapping>
+where
+    TMapping: DateRangeMapping,
+    TNewMapping: DateRangeMapping,
+{
+    match bound {
+        Bound::Included(date) => Bound::Included(Date::remap(date)),
+        Bound::Excluded(date) => Bound::Excluded(Date::remap(date)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+impl<TMapping> DateRangeFieldType<TMapping> for DateRange<TMapping> where TMapping: DateRangeMapping {}
+
+impl<TMapping> Serialize for DateRange<TMapping>
+where
+    TMapping: DateRangeMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = [is_bounded(&self.lower), is_bounded(&self.upper)]
+            .iter()
+            .filter(|bounded| **bounded)
+            .count();
+
+        let mut state = try!(serializer.serialize_map(Some(len)));
+
+        match self.lower {
+            Bound::Included(ref date) => try!(state.serialize_entry("gte", date)),
+            Bound::Excluded(ref date) => try!(state.serialize_entry("gt", date)),
+            Bound::Unbounded => (),
+        }
+
+        match self.upper {
+            Bound::Included(ref date) => try!(state.serialize_entry("lte", date)),
+            Bound::Excluded(ref date) => try!(state.serialize_entry("lt", date)),
+            Bound::Unbounded => (),
+        }
+
+        state.end()
+    }
+}
+
+impl<'de, TMapping> Deserialize<'de> for DateRange<TMapping>
+where
+    TMapping: DateRangeMapping,
+{
+    fn deserialize<D>(deserializer: D) -> Result<DateRange<TMapping>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DateRangeVisitor<TMapping> {
+            _t: PhantomData<TMapping>,
+        }
+
+        impl<'de, TMapping> Visitor<'de> for DateRangeVisitor<TMapping>
+        where
+            TMapping: DateRangeMapping,
+        {
+            type Value = DateRange<TMapping>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    "a json object with gte, gt, lte or lt dates formatted as {}",
+                    TMapping::Format::name()
+                )
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<DateRange<TMapping>, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let (lower, upper) = try!(deserialize_bounds(map));
+
+                Ok(DateRange::new(lower, upper))
+            }
+        }
+
+        deserializer.deserialize_map(DateRangeVisitor::<TMapping> { _t: PhantomData })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::ops::Bound;
+
+    use prelude::*;
+
+    fn closed_range<TMapping>() -> DateRange<TMapping>
+    where
+        TMapping: DateRangeMapping,
+    {
+        DateRange::new(
+            Bound::Included(Date::build(2015, 5, 13, 0, 0, 0, 0)),
+            Bound::Excluded(Date::build(2015, 5, 14, 0, 0, 0, 0)),
+        )
+    }
+
+    #[test]
+    fn serialise_elastic_date_range() {
+        let range: DateRange<DefaultDateRangeMapping> = closed_range();
+
+        let ser = serde_json::to_string(&range).unwrap();
+
+        assert_eq!(
+            r#"{"gte":"20150513T000000.000Z","lt":"20150514T000000.000Z"}"#,
+            ser
+        );
+    }
+
+    #[test]
+    fn serialise_elastic_date_range_unbounded() {
+        let range: DateRange<DefaultDateRangeMapping<EpochMillis>> = DateRange::new(
+            Bound::Excluded(Date::build(2015, 5, 13, 0, 0, 0, 0)),
+            Bound::Unbounded,
+        );
+
+        let ser = serde_json::to_string(&range).unwrap();
+
+        assert_eq!(r#"{"gt":1431475200000}"#, ser);
+
+        let range: DateRange<DefaultDateRangeMapping> = DateRange::new(Bound::Unbounded, Bound::Unbounded);
+
+        assert_eq!("{}", serde_json::to_string(&range).unwrap());
+    }
+
+    #[test]
+    fn deserialise_elastic_date_range() {
+        let range: DateRange<DefaultDateRangeMapping> =
+            serde_json::from_str(r#"{"lt":"20150514T000000.000Z","gte":"20150513T000000.000Z"}"#)
+                .unwrap();
+
+        assert_eq!(closed_range::<DefaultDateRangeMapping>(), range);
+    }
+
+    #[test]
+    fn deserialise_elastic_date_range_unbounded() {
+        let range: DateRange<DefaultDateRangeMapping<EpochMillis>> =
+            serde_json::from_str(r#"{"gte":null,"lte":1431475200000}"#).unwrap();
+
+        assert!(match *range.lower() {
+            Bound::Unbounded => true,
+            _ => false,
+        });
+        assert!(match *range.upper() {
+            Bound::Included(ref date) => {
+                let expected: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
+
+                *date == expected
+            }
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn deserialise_elastic_date_range_duplicate_bound() {
+        let range = serde_json::from_str::<DateRange<DefaultDateRangeMapping>>(
+            r#"{"gte":"20150513T000000.000Z","gt":"20150513T000000.000Z"}"#,
+        );
+
+        assert!(range.is_err());
+    }
+
+    #[test]
+    fn deserialise_elastic_date_range_duplicate_null_bound() {
+        let range = serde_json::from_str::<DateRange<DefaultDateRangeMapping>>(
+            r#"{"gte":null,"gt":"20150513T000000.000Z"}"#,
+        );
+
+        assert!(range.is_err());
+
+        let range = serde_json::from_str::<DateRange<DefaultDateRangeMapping>>(
+            r#"{"lt":null,"lte":null}"#,
+        );
+
+        assert!(range.is_err());
+    }
+
+    #[test]
+    fn deserialise_elastic_date_range_unknown_field() {
+        let range = serde_json::from_str::<DateRange<DefaultDateRangeMapping>>(
+            r#"{"from":"20150513T000000.000Z"}"#,
+        );
+
+        assert!(range.is_err());
+    }
+
+    #[test]
+    fn serialise_elastic_date_range_roundtrip() {
+        let range: DateRange<DefaultDateRangeMapping<EpochMillis>> = closed_range();
+
+        let ser = serde_json::to_string(&range).unwrap();
+        let de: DateRange<DefaultDateRangeMapping<EpochMillis>> = serde_json::from_str(&ser).unwrap();
+
+        assert_eq!(range, de);
+    }
+}
@@ -0,0 +1,243 @@
+/*! Mapping for the Elasticsearch `date_range` type. */
+
+use date::{
+    DateFormat,
+    DefaultDateFormat,
+};
+use std::marker::PhantomData;
+
+/** A field that will be mapped as a `date_range`. */
+pub trait DateRangeFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `date_range` type.
+
+# Examples
+
+Define a custom `DateRangeMapping`:
+
+```
+# #[macro_use]
+# extern crate elastic_types;
+# extern crate serde;
+# use elastic_types::prelude::*;
+#[derive(Default)]
+struct MyDateRangeMapping;
+impl DateRangeMapping for MyDateRangeMapping {
+    type Format = EpochMillis;
+
+    //Overload the mapping functions here
+    fn boost() -> Option<f32> {
+        Some(1.5)
+    }
+}
+# fn main() {}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use]
+# extern crate json_str;
+# #[macro_use]
+# extern crate elastic_types;
+# extern crate serde;
+# extern crate serde_json;
+# use elastic_types::prelude::*;
+# #[derive(Default)]
+# struct MyDateRangeMapping;
+# impl DateRangeMapping for MyDateRangeMapping {
+#     type Format = EpochMillis;
+#     fn boost() -> Option<f32> {
+#         Some(1.5)
+#     }
+# }
+# fn main() {
+# let mapping = elastic_types::derive::standalone_field_ser(MyDateRangeMapping).unwrap();
+# let json = json_str!(
+{
+    "type": "date_range",
+    "format": "epoch_millis",
+    "boost": 1.5
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+*/
+pub trait DateRangeMapping {
+    /**
+    The date format bound to this mapping.
+
+    The value of `Format::name()` is what's sent to Elasticsearch as the format to use.
+    This is also used to serialise and deserialise the bounds of a `DateRange`.
+    */
+    type Format: DateFormat;
+
+    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
+    fn boost() -> Option<f32> {
+        None
+    }
+
+    /**
+    Try to convert strings to numbers and truncate fractions for integers.
+    Accepts `true` (default) and `false`.
+    */
+    fn coerce() -> Option<bool> {
+        None
+    }
+
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /**
+    Whether the field value should be stored and retrievable separately from the `_source` field.
+    Accepts `true` or `false` (default).
+    */
+    fn store() -> Option<bool> {
+        None
+    }
+}
+
+/** Default mapping for a `date_range`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultDateRangeMapping<TFormat = DefaultDateFormat>
+where
+    TFormat: DateFormat,
+{
+    _f: PhantomData<TFormat>,
+}
+
+impl<TFormat> DateRangeMapping for DefaultDateRangeMapping<TFormat>
+where
+    TFormat: DateFormat,
+{
+    type Format = TFormat;
+}
+
+mod private {
+    use super::{
+        DateRangeFieldType,
+        DateRangeMapping,
+    };
+    use date::DateFormat;
+    use private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::ser::SerializeStruct;
+    use serde::{
+        Serialize,
+        Serializer,
+    };
+
+    impl<TField, TMapping> FieldType<TMapping, DateRangePivot> for TField
+    where
+        TField: DateRangeFieldType<TMapping> + Serialize,
+        TMapping: DateRangeMapping,
+    {
+    }
+
+    #[derive(Default)]
+    pub struct DateRangePivot;
+
+    impl<TMapping, TFormat> FieldMapping<DateRangePivot> for TMapping
+    where
+        TMapping: DateRangeMapping<Format = TFormat>,
+        TFormat: DateFormat,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, DateRangePivot>;
+
+        fn data_type() -> &'static str {
+            "date_range"
+        }
+    }
+
+    impl<TMapping, TFormat> StaticSerialize for SerializeFieldMapping<TMapping, DateRangePivot>
+    where
+        TMapping: FieldMapping<DateRangePivot> + DateRangeMapping<Format = TFormat>,
+        TFormat: DateFormat,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = try!(serializer.serialize_struct("mapping", 6));
+
+            try!(state.serialize_field("type", TMapping::data_type()));
+            try!(state.serialize_field("format", TMapping::Format::name()));
+
+            ser_field!(state, "boost", TMapping::boost());
+            ser_field!(state, "coerce", TMapping::coerce());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "store", TMapping::store());
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use prelude::*;
+    use private::field;
+
+    #[derive(Default, Clone)]
+    pub struct MyDateRangeMapping;
+    impl DateRangeMapping for MyDateRangeMapping {
+        type Format = EpochMillis;
+
+        fn boost() -> Option<f32> {
+            Some(1.01)
+        }
+
+        fn coerce() -> Option<bool> {
+            Some(true)
+        }
+
+        fn index() -> Option<bool> {
+            Some(false)
+        }
+
+        fn store() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = serde_json::to_string(&field::serialize(
+            DefaultDateRangeMapping::<DefaultDateFormat>::default(),
+        ))
+        .unwrap();
+
+        let expected = json_str!({
+            "type": "date_range",
+            "format": "basic_date_time"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_custom() {
+        let ser = serde_json::to_string(&field::serialize(MyDateRangeMapping)).unwrap();
+
+        let expected = json_str!({
+            "type": "date_range",
+            "format": "epoch_millis",
+            "boost": 1.01,
+            "coerce": true,
+            "index": false,
+            "store": true
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
@@ -0,0 +1,58 @@
+/*!
+Implementation of the Elasticsearch `date_range` type.
+
+A `date_range` stores a range of dates in a single field, like the dates a booking covers.
+The `DateRange<M>` type holds an optional lower and upper bound, each of which can be inclusive or exclusive.
+Either bound can be left open with `Bound::Unbounded`.
+
+Ranges are serialised as an object with `gte`, `gt`, `lte` or `lt` keys.
+Unbounded sides are left out of the object.
+
+# Examples
+
+Map with a default `date_range`:
+
+```
+# use elastic_types::prelude::*;
+struct MyType {
+    pub field: DateRange<DefaultDateRangeMapping>
+}
+```
+
+Map with a custom `date_range`:
+
+```
+# extern crate serde;
+# #[macro_use]
+# extern crate elastic_types;
+# fn main() {
+# use elastic_types::prelude::*;
+# #[derive(Default)]
+# struct MyDateRangeMapping;
+# impl DateRangeMapping for MyDateRangeMapping { type Format = EpochMillis; }
+struct MyType {
+    pub field: DateRange<MyDateRangeMapping>
+}
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/range.html)
+*/
+
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `date_range` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::impls::*;
+    pub use super::mapping::*;
+}
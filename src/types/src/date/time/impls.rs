@@ -0,0 +1,245 @@
+use super::mapping::{
+    TimeFieldType,
+    TimeMapping,
+};
+use super::TimeFormat;
+use chrono::NaiveTime;
+use serde::de::{
+    Error,
+    Visitor,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::borrow::Borrow;
+use std::fmt::{
+    Display,
+    Formatter,
+    Result as FmtResult,
+};
+use std::marker::PhantomData;
+
+/**
+An Elasticsearch `date` type that only has a `time` component.
+
+The [format](trait.TimeFormat.html) is provided by the mapping.
+This struct wraps up a `chrono::NaiveTime` struct, so there's no date component to serialise.
+
+# Examples
+
+Defining a time using the default format:
+
+```
+# extern crate elastic_types;
+# extern crate chrono;
+# use elastic_types::prelude::*;
+# fn main() {
+use chrono::NaiveTime;
+
+let time: Time<DefaultTimeMapping> = Time::new(NaiveTime::from_hms(9, 30, 0));
+# }
+```
+
+Accessing the values of a time:
+
+```
+# use elastic_types::prelude::*;
+let time: Time<DefaultTimeMapping> = Time::build(9, 30, 0, 0);
+
+println!("{}:{}:{}", time.hour(), time.minute(), time.second());
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Time<TMapping>
+where
+    TMapping: TimeMapping,
+{
+    value: NaiveTime,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> Time<TMapping>
+where
+    TMapping: TimeMapping,
+{
+    /**
+    Creates a new `Time` from the given `chrono::NaiveTime`.
+
+    # Examples
+
+    ```
+    # extern crate elastic_types;
+    # extern crate chrono;
+    # use elastic_types::prelude::*;
+    # fn main() {
+    use chrono::NaiveTime;
+
+    let time: Time<DefaultTimeMapping> = Time::new(NaiveTime::from_hms(9, 30, 0));
+    # }
+    ```
+    */
+    pub fn new<I>(time: I) -> Self
+    where
+        I: Into<NaiveTime>,
+    {
+        Time {
+            value: time.into(),
+            _m: PhantomData,
+        }
+    }
+
+    /**
+    Creates a `Time` from the given primitives:
+
+    ```
+    # use elastic_types::prelude::*;
+    let time: Time<DefaultTimeMapping> = Time::build(16, 45, 8, 886);
+    ```
+    */
+    pub fn build(hour: u32, minute: u32, second: u32, milli: u32) -> Self {
+        Time::new(NaiveTime::from_hms_milli(hour, minute, second, milli))
+    }
+
+    /**
+    Change the format/mapping of this time.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let time: Time<DefaultTimeMapping<TimeOfDay>> = Time::build(16, 45, 8, 886);
+
+    let othertime: Time<DefaultTimeMapping<TimeOfDayNoMillis>> = Time::remap(time);
+    ```
+    */
+    pub fn remap<TNewMapping>(time: Time<TMapping>) -> Time<TNewMapping>
+    where
+        TNewMapping: TimeMapping,
+    {
+        Time::new(time.value)
+    }
+}
+
+impl<TMapping> TimeFieldType<TMapping> for Time<TMapping> where TMapping: TimeMapping {}
+
+impl_mapping_type!(NaiveTime, Time, TimeMapping);
+
+impl<TMapping> Display for Time<TMapping>
+where
+    TMapping: TimeMapping,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        TMapping::Format::format(&self.value).fmt(f)
+    }
+}
+
+impl<TMapping> Serialize for Time<TMapping>
+where
+    TMapping: TimeMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&TMapping::Format::format(&self.value))
+    }
+}
+
+impl<'de, TMapping> Deserialize<'de> for Time<TMapping>
+where
+    TMapping: TimeMapping,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Time<TMapping>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TimeVisitor<TMapping> {
+            _t: PhantomData<TMapping>,
+        }
+
+        impl<'de, TMapping> Visitor<'de> for TimeVisitor<TMapping>
+        where
+            TMapping: TimeMapping,
+        {
+            type Value = Time<TMapping>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    "a json string formatted as {}",
+                    TMapping::Format::name()
+                )
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Time<TMapping>, E>
+            where
+                E: Error,
+            {
+                let result = TMapping::Format::parse(v).map_err(|err| E::custom(format!("{}", err)))?;
+
+                Ok(Time::new(result))
+            }
+        }
+
+        deserializer.deserialize_any(TimeVisitor::<TMapping> { _t: PhantomData })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+    use serde_json;
+
+    use prelude::*;
+
+    #[test]
+    fn can_change_time_mapping() {
+        fn takes_no_millis(_: Time<DefaultTimeMapping<TimeOfDayNoMillis>>) -> bool {
+            true
+        }
+
+        let time: Time<DefaultTimeMapping> = Time::build(14, 55, 2, 478);
+
+        assert!(takes_no_millis(Time::remap(time)));
+    }
+
+    #[test]
+    fn serialise_elastic_time() {
+        let time: Time<DefaultTimeMapping> = Time::build(14, 55, 2, 478);
+
+        let ser = serde_json::to_string(&time).unwrap();
+
+        assert_eq!(r#""14:55:02.478Z""#, ser);
+    }
+
+    #[test]
+    fn serialise_elastic_time_no_millis() {
+        let time: Time<DefaultTimeMapping<TimeOfDayNoMillis>> = Time::build(14, 55, 2, 478);
+
+        let ser = serde_json::to_string(&time).unwrap();
+
+        assert_eq!(r#""14:55:02Z""#, ser);
+    }
+
+    #[test]
+    fn deserialise_elastic_time() {
+        let time: Time<DefaultTimeMapping> = serde_json::from_str(r#""14:55:02.478Z""#).unwrap();
+
+        assert_eq!(NaiveTime::from_hms_milli(14, 55, 2, 478), time);
+    }
+
+    #[test]
+    fn deserialise_elastic_time_with_date_is_err() {
+        let time: Result<Time<DefaultTimeMapping>, _> =
+            serde_json::from_str(r#""2015-07-03T14:55:02.478Z""#);
+
+        assert!(time.is_err());
+    }
+}
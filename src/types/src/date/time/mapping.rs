@@ -0,0 +1,276 @@
+/*! Mapping for the Elasticsearch `date` type with a time-only format. */
+
+use super::{
+    DefaultTimeFormat,
+    Time,
+    TimeFormat,
+};
+use std::marker::PhantomData;
+
+/** A field that will be mapped as a time-only `date`. */
+pub trait TimeFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a time-only `date` type.
+
+# Examples
+
+Define a custom `TimeMapping`:
+
+```
+# #[macro_use]
+# extern crate elastic_types;
+# extern crate serde;
+# use elastic_types::prelude::*;
+#[derive(Default)]
+struct MyTimeMapping;
+impl TimeMapping for MyTimeMapping {
+    type Format = TimeOfDayNoMillis;
+
+    //Overload the mapping functions here
+    fn boost() -> Option<f32> {
+        Some(1.5)
+    }
+}
+# fn main() {}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use]
+# extern crate json_str;
+# #[macro_use]
+# extern crate elastic_types;
+# extern crate serde;
+# extern crate serde_json;
+# use elastic_types::prelude::*;
+# #[derive(Default)]
+# struct MyTimeMapping;
+# impl TimeMapping for MyTimeMapping {
+#     type Format = TimeOfDayNoMillis;
+#     fn boost() -> Option<f32> {
+#         Some(1.5)
+#     }
+# }
+# fn main() {
+# let mapping = elastic_types::derive::standalone_field_ser(MyTimeMapping).unwrap();
+# let json = json_str!(
+{
+    "type": "date",
+    "format": "time_no_millis",
+    "boost": 1.5
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+*/
+pub trait TimeMapping {
+    /**
+    The time format bound to this mapping.
+
+    The value of `Format::name()` is what's sent to Elasticsearch as the format to use.
+    This is also used to serialise and deserialise formatted `Time`s.
+    */
+    type Format: TimeFormat;
+
+    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
+    fn boost() -> Option<f32> {
+        None
+    }
+
+    /**
+    Should the field be stored on disk in a column-stride fashion,
+    so that it can later be used for sorting, aggregations, or scripting?
+    Accepts `true` (default) or `false`.
+    */
+    fn doc_values() -> Option<bool> {
+        None
+    }
+
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /**
+    Whether the field value should be stored and retrievable separately from the `_source` field.
+    Accepts `true` or `false` (default).
+    */
+    fn store() -> Option<bool> {
+        None
+    }
+
+    /**
+    If `true`, malformed times are ignored.
+    If `false` (default), malformed times throw an exception and reject the whole document.
+    */
+    fn ignore_malformed() -> Option<bool> {
+        None
+    }
+
+    /**
+    Accepts a time value in the configured format as the field which is substituted for any explicit null values.
+    Defaults to `null`, which means the field is treated as missing.
+    */
+    fn null_value() -> Option<Time<Self>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+/** Default mapping for a time-only `date`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultTimeMapping<TFormat = DefaultTimeFormat>
+where
+    TFormat: TimeFormat,
+{
+    _f: PhantomData<TFormat>,
+}
+
+impl<TFormat> TimeMapping for DefaultTimeMapping<TFormat>
+where
+    TFormat: TimeFormat,
+{
+    type Format = TFormat;
+}
+
+mod private {
+    use super::{
+        TimeFieldType,
+        TimeMapping,
+    };
+    use date::time::TimeFormat;
+    use private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::ser::SerializeStruct;
+    use serde::{
+        Serialize,
+        Serializer,
+    };
+
+    impl<TField, TMapping> FieldType<TMapping, TimePivot> for TField
+    where
+        TField: TimeFieldType<TMapping> + Serialize,
+        TMapping: TimeMapping,
+    {
+    }
+
+    #[derive(Default)]
+    pub struct TimePivot;
+
+    impl<TMapping, TFormat> FieldMapping<TimePivot> for TMapping
+    where
+        TMapping: TimeMapping<Format = TFormat>,
+        TFormat: TimeFormat,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, TimePivot>;
+
+        fn data_type() -> &'static str {
+            "date"
+        }
+    }
+
+    impl<TMapping, TFormat> StaticSerialize for SerializeFieldMapping<TMapping, TimePivot>
+    where
+        TMapping: FieldMapping<TimePivot> + TimeMapping<Format = TFormat>,
+        TFormat: TimeFormat,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = try!(serializer.serialize_struct("mapping", 8));
+
+            try!(state.serialize_field("type", TMapping::data_type()));
+            try!(state.serialize_field("format", TMapping::Format::name()));
+
+            ser_field!(state, "boost", TMapping::boost());
+            ser_field!(state, "doc_values", TMapping::doc_values());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "store", TMapping::store());
+            ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_field!(state, "null_value", TMapping::null_value());
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use prelude::*;
+    use private::field;
+
+    #[derive(Default, Clone)]
+    pub struct MyTimeMapping;
+    impl TimeMapping for MyTimeMapping {
+        type Format = TimeOfDayNoMillis;
+
+        fn null_value() -> Option<Time<Self>> {
+            Some(Time::build(9, 30, 0, 0))
+        }
+
+        fn boost() -> Option<f32> {
+            Some(1.01)
+        }
+
+        fn index() -> Option<bool> {
+            Some(true)
+        }
+
+        fn doc_values() -> Option<bool> {
+            Some(true)
+        }
+
+        fn store() -> Option<bool> {
+            Some(true)
+        }
+
+        fn ignore_malformed() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = serde_json::to_string(&field::serialize(
+            DefaultTimeMapping::<DefaultTimeFormat>::default(),
+        ))
+        .unwrap();
+
+        let expected = json_str!({
+            "type": "date",
+            "format": "time"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_custom() {
+        let ser = serde_json::to_string(&field::serialize(MyTimeMapping)).unwrap();
+
+        let expected = json_str!({
+            "type": "date",
+            "format": "time_no_millis",
+            "boost": 1.01,
+            "doc_values": true,
+            "index": true,
+            "store": true,
+            "ignore_malformed": true,
+            "null_value": "09:30:00Z"
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
@@ -0,0 +1,231 @@
+/*!
+Implementation of the Elasticsearch `date` type for time-only values.
+
+Some fields only store a time of day, like the time a store opens.
+The `Time<M>` type wraps a `chrono::NaiveTime` so these fields don't need to be modelled as a full `Date`.
+Times are mapped as a `date` in Elasticsearch, but only use time-only formats like `time` or `time_no_millis`.
+They never have a date component added when they're serialised or deserialised.
+
+Times are always stored in `Utc`.
+If a time is parsed with an offset then it's shifted to `Utc`, wrapping around midnight if needed.
+
+# Examples
+
+Map with a default `time`:
+
+```
+# use elastic_types::prelude::*;
+struct MyType {
+    pub field: Time<DefaultTimeMapping>
+}
+```
+
+Map with a custom `time`:
+
+```
+# extern crate serde;
+# #[macro_use]
+# extern crate elastic_types;
+# use std::marker::PhantomData;
+# fn main() {
+# use elastic_types::prelude::*;
+# #[derive(Default)]
+# struct MyTimeMapping;
+# impl TimeMapping for MyTimeMapping { type Format = TimeOfDayNoMillis; }
+struct MyType {
+    pub field: Time<MyTimeMapping>
+}
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+*/
+
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+use chrono::format::{
+    self,
+    Fixed,
+    Item,
+    Numeric,
+    Pad,
+    Parsed,
+};
+use chrono::{
+    Duration,
+    NaiveTime,
+};
+
+use super::{
+    FormattedDate,
+    ParseError,
+};
+
+/** The default `time` format (`time`). */
+pub type DefaultTimeFormat = TimeOfDay;
+
+/**
+A format used for parsing and formatting times.
+
+This is the time-only equivalent of `DateFormat`.
+A `chrono::NaiveTime` is used as the input for formatting and output for parsing.
+*/
+pub trait TimeFormat {
+    /** Parses a time string to a `chrono::NaiveTime` result. */
+    fn parse(time: &str) -> Result<NaiveTime, ParseError>;
+
+    /** Formats a given `chrono::NaiveTime` as a string. */
+    fn format<'a>(time: &'a NaiveTime) -> FormattedDate<'a>;
+
+    /**
+    The name of the format.
+
+    This is the string used when defining the format in the field mapping.
+    */
+    fn name() -> &'static str;
+}
+
+/** Format for `time`: a time with milliseconds and a `Utc` offset, like `HH:mm:ss.SSSZ`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct TimeOfDay;
+
+impl TimeFormat for TimeOfDay {
+    fn parse(time: &str) -> Result<NaiveTime, ParseError> {
+        parse_time(time, time_items(true, PARSE_OFFSET))
+    }
+
+    fn format<'a>(time: &'a NaiveTime) -> FormattedDate<'a> {
+        time.format_with_items(time_items(true, FORMAT_OFFSET).into_iter())
+            .into()
+    }
+
+    fn name() -> &'static str {
+        "time"
+    }
+}
+
+/** Format for `time_no_millis`: a time without milliseconds and a `Utc` offset, like `HH:mm:ssZ`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct TimeOfDayNoMillis;
+
+impl TimeFormat for TimeOfDayNoMillis {
+    fn parse(time: &str) -> Result<NaiveTime, ParseError> {
+        parse_time(time, time_items(false, PARSE_OFFSET))
+    }
+
+    fn format<'a>(time: &'a NaiveTime) -> FormattedDate<'a> {
+        time.format_with_items(time_items(false, FORMAT_OFFSET).into_iter())
+            .into()
+    }
+
+    fn name() -> &'static str {
+        "time_no_millis"
+    }
+}
+
+// Times are parsed with any offset, but always formatted in `Utc`.
+// A `NaiveTime` has no offset of its own to format, so `Z` is written literally.
+const PARSE_OFFSET: Item<'static> = Item::Fixed(Fixed::TimezoneOffsetZ);
+const FORMAT_OFFSET: Item<'static> = Item::Literal("Z");
+
+fn time_items<'a>(millis: bool, offset: Item<'a>) -> Vec<Item<'a>> {
+    let mut items = vec![
+        Item::Numeric(Numeric::Hour, Pad::Zero),
+        Item::Literal(":"),
+        Item::Numeric(Numeric::Minute, Pad::Zero),
+        Item::Literal(":"),
+        Item::Numeric(Numeric::Second, Pad::Zero),
+    ];
+
+    if millis {
+        items.push(Item::Fixed(Fixed::Nanosecond3));
+    }
+
+    items.push(offset);
+
+    items
+}
+
+fn parse_time<'a>(time: &str, fmt: Vec<Item<'a>>) -> Result<NaiveTime, ParseError> {
+    let mut parsed = Parsed::new();
+    format::parse(&mut parsed, time, fmt.into_iter())?;
+
+    let time = parsed.to_naive_time()?;
+
+    // Shift the time to `Utc` without adding a date to it
+    let offset = Duration::seconds(parsed.offset.unwrap_or(0) as i64);
+    let (time, _) = time.overflowing_sub_signed(offset);
+
+    Ok(time)
+}
+
+pub mod prelude {
+    /*!
+    Includes all types for the `time` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::impls::*;
+    pub use super::mapping::*;
+    pub use super::{
+        DefaultTimeFormat,
+        TimeFormat,
+        TimeOfDay,
+        TimeOfDayNoMillis,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+
+    use prelude::*;
+
+    #[test]
+    fn time_of_day() {
+        let time = TimeOfDay::parse("14:55:02.478Z").unwrap();
+
+        assert_eq!(NaiveTime::from_hms_milli(14, 55, 2, 478), time);
+
+        let fmtd = TimeOfDay::format(&time).to_string();
+        assert_eq!("14:55:02.478Z", &fmtd);
+    }
+
+    #[test]
+    fn time_of_day_name() {
+        assert_eq!("time", TimeOfDay::name());
+    }
+
+    #[test]
+    fn time_of_day_no_millis() {
+        let time = TimeOfDayNoMillis::parse("14:55:02Z").unwrap();
+
+        assert_eq!(NaiveTime::from_hms(14, 55, 2), time);
+
+        let fmtd = TimeOfDayNoMillis::format(&time).to_string();
+        assert_eq!("14:55:02Z", &fmtd);
+    }
+
+    #[test]
+    fn time_of_day_no_millis_name() {
+        assert_eq!("time_no_millis", TimeOfDayNoMillis::name());
+    }
+
+    #[test]
+    fn time_with_offset_is_shifted_to_utc() {
+        let time = TimeOfDayNoMillis::parse("01:30:00+02:00").unwrap();
+
+        assert_eq!(NaiveTime::from_hms(23, 30, 0), time);
+    }
+
+    #[test]
+    fn time_with_date_is_err() {
+        assert!(TimeOfDayNoMillis::parse("2015-07-03T14:55:02Z").is_err());
+    }
+}
@@ -11,10 +11,12 @@ use chrono::format::{
 };
 use chrono::{
     DateTime,
+    Timelike,
     Utc,
 };
 use serde::Serialize;
 use serde_json;
+use std::borrow::Cow;
 
 use private::field::{
     FieldMapping,
@@ -45,6 +47,7 @@ pub use chrono::format::{
     Numeric,
     Pad,
 };
+pub use chrono::NaiveTime;
 pub use serde::ser::SerializeStruct;
 
 /** Serialise a field mapping as a field using the given serialiser. */
@@ -78,13 +81,26 @@ where
 
 /** Parse a date string using an owned slice of items. */
 pub fn parse_from_tokens<'a>(date: &str, fmt: Vec<Item<'a>>) -> Result<DateValue, ParseError> {
+    parse_from_tokens_with_default_time(date, fmt, NaiveTime::from_hms(0, 0, 0))
+}
+
+/** Parse a date string using an owned slice of items, assuming the given time if the date string doesn't contain one. */
+pub fn parse_from_tokens_with_default_time<'a>(
+    date: &str,
+    fmt: Vec<Item<'a>>,
+    time: NaiveTime,
+) -> Result<DateValue, ParseError> {
+    let date = normalise_fractional_separator(date, &fmt);
+
     let mut parsed = Parsed::new();
-    match format::parse(&mut parsed, date, fmt.into_iter()) {
+    match format::parse(&mut parsed, &date, fmt.iter().cloned()) {
         Ok(_) => {
             // If the parsed result doesn't contain any time, set it to the default
             if parsed.hour_mod_12.is_none() {
-                let _ = parsed.set_hour(0);
-                let _ = parsed.set_minute(0);
+                let _ = parsed.set_hour(time.hour() as i64);
+                let _ = parsed.set_minute(time.minute() as i64);
+                let _ = parsed.set_second(time.second() as i64);
+                let _ = parsed.set_nanosecond(time.nanosecond() as i64);
             }
 
             // Set the DateTime result
@@ -94,7 +110,136 @@ pub fn parse_from_tokens<'a>(date: &str, fmt: Vec<Item<'a>>) -> Result<DateValue
 
             Ok(date.into())
         }
-        Err(e) => Err(e.into()),
+        Err(e) => {
+            let (position, expected) = locate_parse_error(&date, &fmt);
+
+            Err(ParseError::at_position(
+                e,
+                &date,
+                position,
+                expected.and_then(describe_item),
+            ))
+        }
+    }
+}
+
+/** Parse a date string using each owned slice of items in turn. */
+pub fn parse_from_any_tokens<'a>(date: &str, fmts: Vec<Vec<Item<'a>>>) -> Result<DateValue, ParseError> {
+    parse_from_any_tokens_with_default_time(date, fmts, NaiveTime::from_hms(0, 0, 0))
+}
+
+/**
+Parse a date string using each owned slice of items in turn, assuming the given time if the date string doesn't contain one.
+
+The first successful result is returned.
+If none of the formats match then the error from the first format is returned.
+*/
+pub fn parse_from_any_tokens_with_default_time<'a>(
+    date: &str,
+    fmts: Vec<Vec<Item<'a>>>,
+    time: NaiveTime,
+) -> Result<DateValue, ParseError> {
+    let mut first_err = None;
+
+    for fmt in fmts {
+        match parse_from_tokens_with_default_time(date, fmt, time) {
+            Ok(date) => return Ok(date),
+            Err(e) => {
+                if first_err.is_none() {
+                    first_err = Some(e);
+                }
+            }
+        }
+    }
+
+    Err(first_err.unwrap_or_else(|| ParseError::from(String::from("no date formats to parse with"))))
+}
+
+/**
+Find how far into the input the format can be matched, and the item that couldn't be matched after that.
+
+The parser doesn't report where it failed, so this matches successively longer runs of items against successively longer prefixes of the input.
+It's only called when parsing has already failed.
+*/
+fn locate_parse_error<'a, 'b>(date: &str, fmt: &'b [Item<'a>]) -> (usize, Option<&'b Item<'a>>) {
+    let (mut position, mut matched) = (0, 0);
+
+    for items in 1..fmt.len() + 1 {
+        let end = (position..date.len() + 1)
+            .rev()
+            .filter(|&end| date.is_char_boundary(end))
+            .find(|&end| {
+                let mut parsed = Parsed::new();
+                format::parse(&mut parsed, &date[..end], fmt[..items].iter().cloned()).is_ok()
+            });
+
+        match end {
+            Some(end) => {
+                position = end;
+                matched = items;
+            }
+            None => break,
+        }
+    }
+
+    (position, fmt.get(matched))
+}
+
+fn describe_item(item: &Item) -> Option<String> {
+    let description = match *item {
+        Item::Literal(s) | Item::Space(s) => return Some(format!("'{}'", s)),
+        Item::Numeric(Numeric::Year, _) => "a year",
+        Item::Numeric(Numeric::Month, _) => "a month",
+        Item::Numeric(Numeric::Day, _) => "a day",
+        Item::Numeric(Numeric::Ordinal, _) => "a day of the year",
+        Item::Numeric(Numeric::Hour, _) => "an hour",
+        Item::Numeric(Numeric::Minute, _) => "a minute",
+        Item::Numeric(Numeric::Second, _) => "a second",
+        Item::Fixed(Fixed::Nanosecond3)
+        | Item::Fixed(Fixed::Nanosecond6)
+        | Item::Fixed(Fixed::Nanosecond9) => "fractional seconds",
+        Item::Fixed(Fixed::TimezoneOffsetZ) => "a timezone offset",
+        _ => return None,
+    };
+
+    Some(description.to_owned())
+}
+
+fn is_fractional_seconds(item: &Item) -> bool {
+    match *item {
+        Item::Fixed(Fixed::Nanosecond3)
+        | Item::Fixed(Fixed::Nanosecond6)
+        | Item::Fixed(Fixed::Nanosecond9) => true,
+        _ => false,
+    }
+}
+
+/**
+Replace a `,` with a `.` where the format expects fractional seconds.
+
+ISO 8601 allows either a `.` or a `,` to separate fractional seconds, but the parser only understands `.`.
+The separator is found by matching the items before the fractional seconds against the input, so any other commas are left alone.
+*/
+fn normalise_fractional_separator<'a>(date: &'a str, fmt: &[Item]) -> Cow<'a, str> {
+    if !date.contains(',') {
+        return Cow::Borrowed(date);
+    }
+
+    let separator = fmt.iter().position(is_fractional_seconds).and_then(|items| {
+        (0..date.len() + 1)
+            .rev()
+            .filter(|&end| date.is_char_boundary(end))
+            .find(|&end| {
+                let mut parsed = Parsed::new();
+                format::parse(&mut parsed, &date[..end], fmt[..items].iter().cloned()).is_ok()
+            })
+    });
+
+    match separator {
+        Some(position) if date[position..].starts_with(',') => {
+            Cow::Owned(format!("{}.{}", &date[..position], &date[position + 1..]))
+        }
+        _ => Cow::Borrowed(date),
     }
 }
 
@@ -488,6 +488,34 @@ mod tests {
         type Properties = CustomType;
     }
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(mapping = "DynamicTemplatesTypeMapping")]
+    pub struct DynamicTemplatesType {
+        pub field: i32,
+    }
+
+    #[derive(PartialEq, Debug, Default)]
+    pub struct DynamicTemplatesTypeMapping;
+    impl ObjectMapping for DynamicTemplatesTypeMapping {
+        type Properties = DynamicTemplatesType;
+
+        fn dynamic_templates() -> Option<Vec<DynamicTemplate>> {
+            Some(vec![
+                DynamicTemplate::new("ids", DefaultKeywordMapping)
+                    .match_mapping_type(MatchMappingType::String)
+                    .match_name("*_id")
+                    .unmatch_name("_id"),
+                DynamicTemplate::new("timestamps", DefaultDateMapping::<EpochMillis>::default())
+                    .match_pattern(MatchPattern::Regex)
+                    .match_name("^.*_at$"),
+                DynamicTemplate::new("meta", DefaultKeywordMapping)
+                    .match_mapping_type(MatchMappingType::Any)
+                    .path_match("meta.*")
+                    .path_unmatch("*.text"),
+            ])
+        }
+    }
+
     #[derive(Serialize, ElasticType)]
     pub struct Wrapped {
         pub field1: Vec<i32>,
@@ -633,6 +661,53 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn serialise_document_with_dynamic_templates() {
+        let ser = serde_json::to_string(&DynamicTemplatesType::index_mapping()).unwrap();
+
+        let expected = json_str!({
+            "dynamic_templates": [
+                {
+                    "ids": {
+                        "match_mapping_type": "string",
+                        "match": "*_id",
+                        "unmatch": "_id",
+                        "mapping": {
+                            "type": "keyword"
+                        }
+                    }
+                },
+                {
+                    "timestamps": {
+                        "match": "^.*_at$",
+                        "match_pattern": "regex",
+                        "mapping": {
+                            "format": "epoch_millis",
+                            "type": "date"
+                        }
+                    }
+                },
+                {
+                    "meta": {
+                        "match_mapping_type": "*",
+                        "path_match": "meta.*",
+                        "path_unmatch": "*.text",
+                        "mapping": {
+                            "type": "keyword"
+                        }
+                    }
+                }
+            ],
+            "properties": {
+                "field": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
     #[test]
     fn serialise_mapping_with_wrapped_types() {
         let ser = serde_json::to_string(&Wrapped::index_mapping()).unwrap();
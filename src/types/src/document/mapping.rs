@@ -1,10 +1,23 @@
 /*! Mapping for Elasticsearch document types. */
 
-use serde::ser::SerializeStruct;
+use serde::ser::{
+    Error,
+    SerializeMap,
+    SerializeStruct,
+};
 use serde::{
     Serialize,
     Serializer,
 };
+use serde_json::{
+    self,
+    Value,
+};
+
+use private::field::{
+    FieldMapping,
+    SerializeFieldMapping,
+};
 
 /** A field that will be mapped as a nested document. */
 pub trait ObjectFieldType {
@@ -53,6 +66,15 @@ pub trait ObjectMapping {
     fn include_in_all() -> Option<bool> {
         None
     }
+
+    /**
+    Templates for mapping fields that are added dynamically.
+
+    Dynamic templates are only serialised for the root object of an indexable document.
+    */
+    fn dynamic_templates() -> Option<Vec<DynamicTemplate>> {
+        None
+    }
 }
 
 /**
@@ -105,6 +127,211 @@ impl Serialize for Dynamic {
     }
 }
 
+/**
+A [dynamic template][docs-dynamic-templates] for mapping fields that are added dynamically.
+
+A dynamic template is applied to new fields that match all of its conditions.
+The mapping for matching fields can be any field mapping, like `DefaultKeywordMapping` or `DefaultDateMapping`.
+
+Dynamic templates are added to a document by implementing `ObjectMapping::dynamic_templates` for its mapping.
+
+# Examples
+
+Map all string fields ending in `_id` as keywords, and all numeric fields ending in `_at` as dates:
+
+```
+# extern crate elastic_types;
+# use elastic_types::prelude::*;
+# fn main() {
+let templates = vec![
+    DynamicTemplate::new("ids", DefaultKeywordMapping)
+        .match_mapping_type(MatchMappingType::String)
+        .match_name("*_id"),
+    DynamicTemplate::new("timestamps", DefaultDateMapping::<EpochMillis>::default())
+        .match_mapping_type(MatchMappingType::Long)
+        .match_name("*_at"),
+];
+# }
+```
+
+[docs-dynamic-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-templates.html
+*/
+#[derive(Debug, Clone)]
+pub struct DynamicTemplate {
+    name: String,
+    match_mapping_type: Option<MatchMappingType>,
+    match_name: Option<String>,
+    unmatch_name: Option<String>,
+    match_pattern: Option<MatchPattern>,
+    path_match: Option<String>,
+    path_unmatch: Option<String>,
+    mapping: fn() -> Result<Value, serde_json::Error>,
+}
+
+impl DynamicTemplate {
+    /** Create a new dynamic template with the given name that maps matching fields using the given mapping. */
+    pub fn new<TMapping, TPivot>(name: impl Into<String>, _: TMapping) -> Self
+    where
+        TMapping: FieldMapping<TPivot>,
+        SerializeFieldMapping<TMapping, TPivot>: Serialize,
+    {
+        DynamicTemplate {
+            name: name.into(),
+            match_mapping_type: None,
+            match_name: None,
+            unmatch_name: None,
+            match_pattern: None,
+            path_match: None,
+            path_unmatch: None,
+            mapping: mapping_value::<TMapping, TPivot>,
+        }
+    }
+
+    /** Only match fields with the given type detected by the JSON parser. */
+    pub fn match_mapping_type(mut self, match_mapping_type: MatchMappingType) -> Self {
+        self.match_mapping_type = Some(match_mapping_type);
+        self
+    }
+
+    /**
+    Only match fields whose name matches the given pattern.
+
+    This is serialised as `match`.
+    */
+    pub fn match_name(mut self, pattern: impl Into<String>) -> Self {
+        self.match_name = Some(pattern.into());
+        self
+    }
+
+    /**
+    Exclude fields whose name matches the given pattern.
+
+    This is serialised as `unmatch`.
+    */
+    pub fn unmatch_name(mut self, pattern: impl Into<String>) -> Self {
+        self.unmatch_name = Some(pattern.into());
+        self
+    }
+
+    /** Set how `match` and `unmatch` patterns are interpreted. */
+    pub fn match_pattern(mut self, match_pattern: MatchPattern) -> Self {
+        self.match_pattern = Some(match_pattern);
+        self
+    }
+
+    /** Only match fields whose full dotted path matches the given pattern. */
+    pub fn path_match(mut self, pattern: impl Into<String>) -> Self {
+        self.path_match = Some(pattern.into());
+        self
+    }
+
+    /** Exclude fields whose full dotted path matches the given pattern. */
+    pub fn path_unmatch(mut self, pattern: impl Into<String>) -> Self {
+        self.path_unmatch = Some(pattern.into());
+        self
+    }
+}
+
+fn mapping_value<TMapping, TPivot>() -> Result<Value, serde_json::Error>
+where
+    TMapping: FieldMapping<TPivot>,
+    SerializeFieldMapping<TMapping, TPivot>: Serialize,
+{
+    serde_json::to_value(&SerializeFieldMapping::<TMapping, TPivot>::default())
+}
+
+impl Serialize for DynamicTemplate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Conditions<'a>(&'a DynamicTemplate);
+
+        impl<'a> Serialize for Conditions<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let template = self.0;
+                let mapping = try!((template.mapping)().map_err(S::Error::custom));
+
+                let mut state = try!(serializer.serialize_struct("dynamic_template", 7));
+
+                ser_field!(state, "match_mapping_type", template.match_mapping_type);
+                ser_field!(state, "match", template.match_name.as_ref());
+                ser_field!(state, "unmatch", template.unmatch_name.as_ref());
+                ser_field!(state, "match_pattern", template.match_pattern);
+                ser_field!(state, "path_match", template.path_match.as_ref());
+                ser_field!(state, "path_unmatch", template.path_unmatch.as_ref());
+                try!(state.serialize_field("mapping", &mapping));
+
+                state.end()
+            }
+        }
+
+        let mut state = try!(serializer.serialize_map(Some(1)));
+        try!(state.serialize_entry(&self.name, &Conditions(self)));
+        state.end()
+    }
+}
+
+/** The type of a new field detected by the JSON parser. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchMappingType {
+    /** Any type. */
+    Any,
+    /** A `true` or `false` value. */
+    Boolean,
+    /** A string that matches one of the date formats for dynamic date detection. */
+    Date,
+    /** A number with a fractional part. */
+    Double,
+    /** A number without a fractional part. */
+    Long,
+    /** An object. */
+    Object,
+    /** A string that isn't detected as a date or number. */
+    String,
+}
+
+impl Serialize for MatchMappingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match *self {
+            MatchMappingType::Any => "*",
+            MatchMappingType::Boolean => "boolean",
+            MatchMappingType::Date => "date",
+            MatchMappingType::Double => "double",
+            MatchMappingType::Long => "long",
+            MatchMappingType::Object => "object",
+            MatchMappingType::String => "string",
+        })
+    }
+}
+
+/** How `match` and `unmatch` patterns in a dynamic template are interpreted. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchPattern {
+    /** Patterns are simple wildcards, like `*_id`. (default). */
+    Simple,
+    /** Patterns are Java regular expressions. */
+    Regex,
+}
+
+impl Serialize for MatchPattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            MatchPattern::Simple => serializer.serialize_str("simple"),
+            MatchPattern::Regex => serializer.serialize_str("regex"),
+        }
+    }
+}
+
 mod private {
     use super::{
         ObjectFieldType,
@@ -231,8 +458,16 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = try!(serializer.serialize_struct("mapping", 1));
+            let dynamic_templates = TMapping::dynamic_templates();
+
+            let len = match dynamic_templates {
+                Some(_) => 2,
+                None => 1,
+            };
+
+            let mut state = try!(serializer.serialize_struct("mapping", len));
 
+            ser_field!(state, "dynamic_templates", dynamic_templates);
             try!(state.serialize_field("properties", &Properties::<TMapping> { _m: PhantomData }));
 
             state.end()
@@ -4,6 +4,7 @@ use serde;
 use serde::Serialize;
 
 /** A unit of measure for distance. */
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DistanceUnit {
     /** For `in`. */
     Inches,
@@ -22,6 +23,7 @@ pub enum DistanceUnit {
 }
 
 /** A distance value paired with a unit of measure. */
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Distance(pub f32, pub DistanceUnit);
 
 impl ToString for Distance {
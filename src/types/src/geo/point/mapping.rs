@@ -126,6 +126,14 @@ pub trait GeoPointMapping {
         None
     }
 
+    /**
+    If `true` (default), three dimension points are accepted but only the latitude and longitude are indexed.
+    If `false`, points with more than two dimensions throw an exception and reject the whole document.
+    */
+    fn ignore_z_value() -> Option<bool> {
+        None
+    }
+
     /**
     Should the `geo-point` also be indexed as `.lat` and `.lon` sub-fields?
     Accepts `true` and `false` (default).
@@ -197,7 +205,7 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = try!(serializer.serialize_struct("mapping", 6));
+            let mut state = try!(serializer.serialize_struct("mapping", 7));
 
             try!(state.serialize_field("type", TMapping::data_type()));
 
@@ -205,6 +213,7 @@ mod private {
             ser_field!(state, "geohash_precision", TMapping::geohash_precision());
             ser_field!(state, "geohash_prefix", TMapping::geohash_prefix());
             ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_field!(state, "ignore_z_value", TMapping::ignore_z_value());
             ser_field!(state, "lat_lon", TMapping::lat_lon());
 
             state.end()
@@ -240,6 +249,10 @@ mod tests {
             Some(true)
         }
 
+        fn ignore_z_value() -> Option<bool> {
+            Some(false)
+        }
+
         fn lat_lon() -> Option<bool> {
             Some(true)
         }
@@ -269,6 +282,7 @@ mod tests {
             "geohash_precision": "50m",
             "geohash_prefix": true,
             "ignore_malformed": true,
+            "ignore_z_value": false,
             "lat_lon": true
         });
 
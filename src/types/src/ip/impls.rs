@@ -16,15 +16,24 @@ use serde::{
 use std::borrow::Borrow;
 use std::error::Error as StdError;
 use std::marker::PhantomData;
-use std::net::Ipv4Addr;
+use std::net::{
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+};
 use std::str::FromStr;
 
+impl IpFieldType<DefaultIpMapping> for IpAddr {}
+
 impl IpFieldType<DefaultIpMapping> for Ipv4Addr {}
 
+impl IpFieldType<DefaultIpMapping> for Ipv6Addr {}
+
 /**
 An Elasticsearch `ip` with a mapping.
 
-Where the mapping isn't custom, you can use the standard library `Ipv4Addr` instead.
+The address can be either IPv4 or IPv6, and is serialised in its canonical string form.
+Where the mapping isn't custom, you can use the standard library `IpAddr`, `Ipv4Addr` or `Ipv6Addr` instead.
 
 # Examples
 
@@ -37,13 +46,23 @@ use elastic_types::ip::Ip;
 
 let ip = Ip::<DefaultIpMapping>::new(Ipv4Addr::new(127, 0, 0, 1));
 ```
+
+Defining an IPv6 `ip` with a mapping:
+
+```
+use std::net::Ipv6Addr;
+use elastic_types::ip::mapping::DefaultIpMapping;
+use elastic_types::ip::Ip;
+
+let ip = Ip::<DefaultIpMapping>::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1));
+```
 */
 #[derive(Debug, Clone, PartialEq)]
 pub struct Ip<TMapping>
 where
     TMapping: IpMapping,
 {
-    value: Ipv4Addr,
+    value: IpAddr,
     _m: PhantomData<TMapping>,
 }
 
@@ -56,7 +75,7 @@ where
 
     # Examples
 
-    Create a new `Ip` from a `Ip4vAddr`:
+    Create a new `Ip` from an `Ipv4Addr`:
 
     ```
     use std::net::Ipv4Addr;
@@ -68,7 +87,7 @@ where
     */
     pub fn new<I>(ip: I) -> Ip<TMapping>
     where
-        I: Into<Ipv4Addr>,
+        I: Into<IpAddr>,
     {
         Ip {
             value: ip.into(),
@@ -109,7 +128,61 @@ where
 
 impl<TMapping> IpFieldType<TMapping> for Ip<TMapping> where TMapping: IpMapping {}
 
-impl_mapping_type!(Ipv4Addr, Ip, IpMapping);
+impl_mapping_type!(IpAddr, Ip, IpMapping);
+
+impl<TMapping> From<Ipv4Addr> for Ip<TMapping>
+where
+    TMapping: IpMapping,
+{
+    fn from(value: Ipv4Addr) -> Self {
+        Ip::new(value)
+    }
+}
+
+impl<TMapping> From<Ipv6Addr> for Ip<TMapping>
+where
+    TMapping: IpMapping,
+{
+    fn from(value: Ipv6Addr) -> Self {
+        Ip::new(value)
+    }
+}
+
+impl<TMapping> PartialEq<Ipv4Addr> for Ip<TMapping>
+where
+    TMapping: IpMapping,
+{
+    fn eq(&self, other: &Ipv4Addr) -> bool {
+        self.value == *other
+    }
+}
+
+impl<TMapping> PartialEq<Ip<TMapping>> for Ipv4Addr
+where
+    TMapping: IpMapping,
+{
+    fn eq(&self, other: &Ip<TMapping>) -> bool {
+        other.value == *self
+    }
+}
+
+impl<TMapping> PartialEq<Ipv6Addr> for Ip<TMapping>
+where
+    TMapping: IpMapping,
+{
+    fn eq(&self, other: &Ipv6Addr) -> bool {
+        self.value == *other
+    }
+}
+
+impl<TMapping> PartialEq<Ip<TMapping>> for Ipv6Addr
+where
+    TMapping: IpMapping,
+{
+    fn eq(&self, other: &Ip<TMapping>) -> bool {
+        other.value == *self
+    }
+}
 
 // Serialize elastic ip
 impl<TMapping> Serialize for Ip<TMapping>
@@ -144,7 +217,7 @@ where
             type Value = Ip<TMapping>;
 
             fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                write!(formatter, "a json string containing an IpV4 address")
+                write!(formatter, "a json string containing an IpV4 or IpV6 address")
             }
 
             fn visit_string<E>(self, v: String) -> Result<Ip<TMapping>, E>
@@ -152,7 +225,7 @@ where
                 E: Error,
             {
                 let de = try!(
-                    Ipv4Addr::from_str(&v).map_err(|e| E::custom(e.description().to_string()))
+                    IpAddr::from_str(&v).map_err(|e| E::custom(e.description().to_string()))
                 );
 
                 Ok(Ip::new(de))
@@ -163,7 +236,7 @@ where
                 E: Error,
             {
                 let de =
-                    try!(Ipv4Addr::from_str(v).map_err(|e| E::custom(e.description().to_string())));
+                    try!(IpAddr::from_str(v).map_err(|e| E::custom(e.description().to_string())));
 
                 Ok(Ip::new(de))
             }
@@ -176,7 +249,10 @@ where
 #[cfg(test)]
 mod tests {
     use serde_json;
-    use std::net::Ipv4Addr;
+    use std::net::{
+        Ipv4Addr,
+        Ipv6Addr,
+    };
 
     use prelude::*;
 
@@ -211,4 +287,27 @@ mod tests {
         assert_eq!(Ipv4Addr::new(127, 0, 0, 1), ip);
     }
 
+    #[test]
+    fn serialise_elastic_ip_v6() {
+        let ip: Ip<DefaultIpMapping> = Ip::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+
+        let ser = serde_json::to_string(&ip).unwrap();
+
+        assert_eq!(r#""2001:db8::1""#, ser);
+    }
+
+    #[test]
+    fn deserialise_elastic_ip_v6() {
+        let ip: Ip<DefaultIpMapping> = serde_json::from_str(r#""2001:db8::1""#).unwrap();
+
+        assert_eq!(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), ip);
+        assert_eq!(ip, Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    }
+
+    #[test]
+    fn deserialise_elastic_ip_invalid() {
+        let ip = serde_json::from_str::<Ip<DefaultIpMapping>>(r#""127.0.0""#);
+
+        assert!(ip.is_err());
+    }
 }
@@ -1,6 +1,6 @@
 /*! Mapping for the Elasticsearch `ip` type. */
 
-use std::net::Ipv4Addr;
+use std::net::IpAddr;
 
 /** A field that will be mapped as an `ip`. */
 pub trait IpFieldType<TMapping> {}
@@ -79,7 +79,7 @@ pub trait IpMapping {
         None
     }
 
-    /** Should the field be searchable? Accepts `not_analyzed` (default) and `no`. */
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
     fn index() -> Option<bool> {
         None
     }
@@ -88,7 +88,7 @@ pub trait IpMapping {
     Accepts a string value which is substituted for any explicit null values.
     Defaults to `null`, which means the field is treated as missing.
     */
-    fn null_value() -> Option<Ipv4Addr> {
+    fn null_value() -> Option<IpAddr> {
         None
     }
 
@@ -170,7 +170,10 @@ mod private {
 #[cfg(test)]
 mod tests {
     use serde_json;
-    use std::net::Ipv4Addr;
+    use std::net::{
+        IpAddr,
+        Ipv4Addr,
+    };
 
     use prelude::*;
     use private::field;
@@ -194,8 +197,8 @@ mod tests {
             Some(true)
         }
 
-        fn null_value() -> Option<Ipv4Addr> {
-            Some(Ipv4Addr::new(127, 0, 0, 1))
+        fn null_value() -> Option<IpAddr> {
+            Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)))
         }
     }
 
@@ -8,9 +8,9 @@ For defining your own ip mapping, see [mapping details](mapping/trait.IpMapping.
 Map with a default `ip`:
 
 ```
-# use std::net::Ipv4Addr;
+# use std::net::IpAddr;
 struct MyType {
-    pub field: std::net::Ipv4Addr
+    pub field: std::net::IpAddr
 }
 ```
 
@@ -426,7 +426,7 @@ In case you're interested, it'll look something like this (minus the whitespace)
         "timestamp": {
             "type": "date",
             "format": "epoch_millis",
-            "null_value": "1435935302478"
+            "null_value": 1435935302478
         },
         "geoip": {
             "type": "nested",
@@ -474,11 +474,19 @@ The following table illustrates the types provided by `elastic_types`:
  `byte`              | `i8`                        | `std`     | [`Byte<M>`](number/index.html)                                                   | -
  `float`             | `f32`                       | `std`     | [`Float<M>`](number/index.html)                                                  | -
  `double`            | `f64`                       | `std`     | [`Double<M>`](number/index.html)                                                 | -
+ `scaled_float`      | -                           | -         | [`ScaledFloat<M>`](number/index.html)                                            | -
+ `integer_range`     | -                           | -         | [`Range<M>`](number/range/index.html)                                            | -
+ `long_range`        | -                           | -         | [`Range<M>`](number/range/index.html)                                            | -
+ `float_range`       | -                           | -         | [`Range<M>`](number/range/index.html)                                            | -
+ `double_range`      | -                           | -         | [`Range<M>`](number/range/index.html)                                            | -
  `keyword`           | -                           | -         | [`Keyword<M>`](string/index.html)                                                | -
  `text`              | `String`                    | `std`     | [`Text<M>`](string/index.html)                                                   | -
  `boolean`           | `bool`                      | `std`     | [`Boolean<M>`](boolean/index.html)                                               | -
- `ip`                | `Ipv4Addr`                  | `std`     | [`Ip<M>`](ip/index.html)                                                         | -
+ `binary`            | -                           | -         | [`Binary<M>`](binary/index.html)                                                 | -
+ `ip`                | `IpAddr`                    | `std`     | [`Ip<M>`](ip/index.html)                                                         | -
  `date`              | `DateTime<Utc>`             | `chrono`  | [`Date<M>`](date/index.html)                                                     | `DateFormat`
+ `date`              | -                           | `chrono`  | [`Time<M>`](date/time/index.html)                                                | `TimeFormat`
+ `date_range`        | -                           | `chrono`  | [`DateRange<M>`](date/range/index.html)                                          | `DateFormat`
  `geo_point`         | `Point`                     | `geo`     | [`GeoPoint<M>`](geo/point/index.html)                                            | `GeoPointFormat`
  `geo_shape`         | -                           | `geojson` | [`GeoShape<M>`](geo/shape/index.html)                                            | -
 
@@ -512,7 +520,10 @@ This is a particularly helpful feature for serialisation.
 // #![deny(warnings)]
 // #![deny(missing_docs)]
 
+extern crate base64;
 extern crate geohash;
+#[macro_use]
+extern crate lazy_static;
 
 #[macro_use]
 extern crate elastic_types_derive;
@@ -532,6 +543,7 @@ extern crate json_str;
 #[macro_use]
 mod private;
 
+pub mod binary;
 pub mod boolean;
 pub mod date;
 pub mod document;
@@ -552,6 +564,7 @@ pub mod prelude {
 
     pub use document::prelude::*;
 
+    pub use binary::prelude::*;
     pub use boolean::prelude::*;
     pub use date::prelude::*;
     pub use geo::prelude::*;
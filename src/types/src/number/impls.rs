@@ -78,6 +78,7 @@ number_type!(Short, ShortMapping, ShortFieldType, i16);
 number_type!(Byte, ByteMapping, ByteFieldType, i8);
 number_type!(Float, FloatMapping, FloatFieldType, f32);
 number_type!(Double, DoubleMapping, DoubleFieldType, f64);
+number_type!(ScaledFloat, ScaledFloatMapping, ScaledFloatFieldType, f64);
 
 #[cfg(test)]
 mod tests {
@@ -109,6 +110,14 @@ mod tests {
     struct MyDoubleMapping;
     impl DoubleMapping for MyDoubleMapping {}
 
+    #[derive(Default)]
+    struct MyScaledFloatMapping;
+    impl ScaledFloatMapping for MyScaledFloatMapping {
+        fn scaling_factor() -> f64 {
+            100.0
+        }
+    }
+
     #[test]
     fn can_change_number_mapping() {
         fn takes_custom_mapping(_: Integer<MyIntegerMapping>) -> bool {
@@ -147,9 +156,13 @@ mod tests {
                 let num = Double::<MyDoubleMapping>::new(1.01f64);
                 serde_json::to_string(&num).unwrap()
             },
+            {
+                let num = ScaledFloat::<MyScaledFloatMapping>::new(1.01f64);
+                serde_json::to_string(&num).unwrap()
+            },
         ];
 
-        let expected_ser = vec!["1", "1", "1", "1", "1.01", "1.01"];
+        let expected_ser = vec!["1", "1", "1", "1", "1.01", "1.01", "1.01"];
 
         let mut success = true;
         for i in 0..ser.len() {
@@ -170,10 +183,19 @@ mod tests {
         let byte_de: Byte<MyByteMapping> = serde_json::from_str("1").unwrap();
         let float_de: Float<MyFloatMapping> = serde_json::from_str("1.01").unwrap();
         let double_de: Double<MyDoubleMapping> = serde_json::from_str("1.01").unwrap();
+        let scaled_float_de: ScaledFloat<MyScaledFloatMapping> = serde_json::from_str("1.01").unwrap();
 
         assert_eq!(
-            (1i32, 1i64, 1i16, 1i8, 1.01f32, 1.01f64),
-            (*int_de, *long_de, *short_de, *byte_de, *float_de, *double_de)
+            (1i32, 1i64, 1i16, 1i8, 1.01f32, 1.01f64, 1.01f64),
+            (
+                *int_de,
+                *long_de,
+                *short_de,
+                *byte_de,
+                *float_de,
+                *double_de,
+                *scaled_float_de
+            )
         );
     }
 }
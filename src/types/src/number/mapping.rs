@@ -106,7 +106,7 @@ macro_rules! number_mapping {
                 None
             }
 
-            /** Should the field be searchable? Accepts `not_analyzed` (default) and `no`. */
+            /** Should the field be searchable? Accepts `true` (default) or `false`. */
             fn index() -> Option<bool> {
                 None
             }
@@ -231,6 +231,165 @@ number_mapping!(
     private_f64
 );
 
+/** A field that will be mapped as a `scaled_float`. */
+pub trait ScaledFloatFieldType<TMapping> {}
+
+/**
+Base `scaled_float` mapping.
+
+A `scaled_float` is stored as a `long`, scaled by a fixed factor.
+There's no default mapping because Elasticsearch requires a `scaling_factor`.
+
+# Examples
+
+Define a custom `ScaledFloatMapping` for prices stored in cents:
+
+```
+# #[macro_use]
+# extern crate elastic_types;
+# extern crate serde;
+# use elastic_types::prelude::*;
+#[derive(Default)]
+struct PriceMapping;
+impl ScaledFloatMapping for PriceMapping {
+    fn scaling_factor() -> f64 {
+        100.0
+    }
+}
+# fn main() {}
+```
+*/
+pub trait ScaledFloatMapping
+where
+    Self: Default,
+{
+    /**
+    The factor values are multiplied by before being stored as a `long`.
+    This is required.
+    */
+    fn scaling_factor() -> f64;
+
+    /** Try to convert strings to numbers and truncate fractions for integers. Accepts `true` (default) and `false`. */
+    fn coerce() -> Option<bool> {
+        None
+    }
+
+    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
+    fn boost() -> Option<f32> {
+        None
+    }
+
+    /**
+    Should the field be stored on disk in a column-stride fashion,
+    so that it can later be used for sorting, aggregations, or scripting?
+    Accepts `true` (default) or `false`.
+    */
+    fn doc_values() -> Option<bool> {
+        None
+    }
+
+    /**
+    If `true`, malformed numbers are ignored. If `false` (default),
+    malformed numbers throw an exception and reject the whole document.
+    */
+    fn ignore_malformed() -> Option<bool> {
+        None
+    }
+
+    /**
+    Whether or not the field value should be included in the `_all` field?
+    Accepts `true` or `false`. Defaults to false if index is set to no,
+    or if a parent object field sets `include_in_all` to false.
+    Otherwise defaults to `true`.
+    */
+    fn include_in_all() -> Option<bool> {
+        None
+    }
+
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /**
+    Accepts a numeric value which is substituted for any explicit null values.
+    Defaults to `null`, which means the field is treated as missing.
+    */
+    fn null_value() -> Option<f64> {
+        None
+    }
+
+    /**
+    Whether the field value should be stored and retrievable separately from the `_source` field.
+    Accepts true or false (default).
+    */
+    fn store() -> Option<bool> {
+        None
+    }
+}
+
+mod private_scaled_f64 {
+    use super::{
+        ScaledFloatFieldType,
+        ScaledFloatMapping,
+    };
+    use private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::ser::SerializeStruct;
+    use serde::Serialize;
+
+    #[derive(Default)]
+    pub struct ScaledFloatFormat;
+
+    impl<TField, TMapping> FieldType<TMapping, ScaledFloatFormat> for TField
+    where
+        TField: ScaledFloatFieldType<TMapping> + Serialize,
+        TMapping: ScaledFloatMapping,
+    {
+    }
+
+    impl<TMapping> FieldMapping<ScaledFloatFormat> for TMapping
+    where
+        TMapping: ScaledFloatMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, ScaledFloatFormat>;
+
+        fn data_type() -> &'static str {
+            "scaled_float"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, ScaledFloatFormat>
+    where
+        TMapping: FieldMapping<ScaledFloatFormat> + ScaledFloatMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ::serde::Serializer,
+        {
+            let mut state = try!(serializer.serialize_struct("mapping", 10));
+
+            try!(state.serialize_field("type", TMapping::data_type()));
+            try!(state.serialize_field("scaling_factor", &TMapping::scaling_factor()));
+
+            ser_field!(state, "coerce", TMapping::coerce());
+            ser_field!(state, "boost", TMapping::boost());
+            ser_field!(state, "doc_values", TMapping::doc_values());
+            ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_field!(state, "include_in_all", TMapping::include_in_all());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "null_value", TMapping::null_value());
+            ser_field!(state, "store", TMapping::store());
+
+            state.end()
+        }
+    }
+}
+
 /** Default mapping for an `integer` type. */
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct DefaultIntegerMapping;
@@ -491,6 +650,46 @@ mod tests {
         }
     }
 
+    #[derive(Default, Clone)]
+    pub struct MyScaledFloatMapping;
+    impl ScaledFloatMapping for MyScaledFloatMapping {
+        fn scaling_factor() -> f64 {
+            100.0
+        }
+
+        fn coerce() -> Option<bool> {
+            Some(true)
+        }
+
+        fn boost() -> Option<f32> {
+            Some(1.1)
+        }
+
+        fn doc_values() -> Option<bool> {
+            Some(false)
+        }
+
+        fn ignore_malformed() -> Option<bool> {
+            Some(true)
+        }
+
+        fn include_in_all() -> Option<bool> {
+            Some(true)
+        }
+
+        fn index() -> Option<bool> {
+            Some(false)
+        }
+
+        fn store() -> Option<bool> {
+            Some(true)
+        }
+
+        fn null_value() -> Option<f64> {
+            Some(1.5)
+        }
+    }
+
     #[test]
     fn serialise_mapping_integer_default() {
         let ser = serde_json::to_string(&field::serialize(DefaultIntegerMapping)).unwrap();
@@ -664,4 +863,24 @@ mod tests {
 
         assert_eq!(expected, ser);
     }
+
+    #[test]
+    fn serialise_mapping_scaled_float_custom() {
+        let ser = serde_json::to_string(&field::serialize(MyScaledFloatMapping)).unwrap();
+
+        let expected = json_str!({
+            "type": "scaled_float",
+            "scaling_factor": 100.0,
+            "coerce": true,
+            "boost": 1.1,
+            "doc_values": false,
+            "ignore_malformed": true,
+            "include_in_all": true,
+            "index": false,
+            "null_value": 1.5,
+            "store": true
+        });
+
+        assert_eq!(expected, ser);
+    }
 }
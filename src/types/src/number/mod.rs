@@ -11,9 +11,13 @@ Rust | Elasticsearch
 `i8` | `byte`
 `f64` | `double`
 `f32` | `float`
+`f64` | `scaled_float`
 
 For mapping a number with the default mapping, you can use the Rust primitive.
 If you need to use a custom mapping, then there is an `Elastic*` type for each number.
+A `scaled_float` always needs a custom mapping for its `scaling_factor`, so it's mapped with `ScaledFloat<M>`.
+
+Ranges of numbers, like `integer_range`, are provided by the [`range`](range/index.html) module.
 
 # Examples
 
@@ -67,6 +71,7 @@ impl IntegerFieldType<DefaultIntegerMapping> for MyIntegerField {}
 */
 
 pub mod mapping;
+pub mod range;
 
 mod impls;
 pub use self::impls::*;
@@ -80,4 +85,5 @@ pub mod prelude {
 
     pub use super::impls::*;
     pub use super::mapping::*;
+    pub use super::range::prelude::*;
 }
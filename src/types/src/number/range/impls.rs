@@ -0,0 +1,277 @@
+use super::mapping::{
+    RangeFieldType,
+    RangeMapping,
+    RangeValue,
+};
+use private::range::{
+    deserialize_bounds,
+    is_bounded,
+};
+use serde::de::{
+    MapAccess,
+    Visitor,
+};
+use serde::ser::SerializeMap;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::fmt::{
+    Formatter,
+    Result as FmtResult,
+};
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+/** A bound of a `Range`, using the range mapping's number. */
+pub type RangeBound<TMapping> = Bound<<TMapping as RangeMapping>::Value>;
+
+/**
+An Elasticsearch numeric range type with optional lower and upper bounds.
+
+Each bound is a `std::ops::Bound`, so it can be inclusive, exclusive or open.
+The number in the range, and so the Elasticsearch range type, is provided by the mapping.
+
+# Examples
+
+Defining a range of ports:
+
+```
+# use elastic_types::prelude::*;
+use std::ops::Bound;
+
+let range: Range<DefaultRangeMapping<i32>> = Range::new(
+    Bound::Included(8000),
+    Bound::Excluded(9000),
+);
+```
+
+Defining a range with no upper bound:
+
+```
+# use elastic_types::prelude::*;
+use std::ops::Bound;
+
+let range: Range<DefaultRangeMapping<f64>> = Range::new(
+    Bound::Included(18.0),
+    Bound::Unbounded,
+);
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/range.html)
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range<TMapping>
+where
+    TMapping: RangeMapping,
+{
+    lower: Bound<TMapping::Value>,
+    upper: Bound<TMapping::Value>,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> Range<TMapping>
+where
+    TMapping: RangeMapping,
+{
+    /**
+    Creates a new `Range` from the given lower and upper bounds.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    use std::ops::Bound;
+
+    let range: Range<DefaultRangeMapping<i64>> = Range::new(
+        Bound::Unbounded,
+        Bound::Included(100),
+    );
+    ```
+    */
+    pub fn new(lower: RangeBound<TMapping>, upper: RangeBound<TMapping>) -> Self {
+        Range {
+            lower: lower,
+            upper: upper,
+            _m: PhantomData,
+        }
+    }
+
+    /** The lower bound of the range, serialised as `gte` or `gt`. */
+    pub fn lower(&self) -> &RangeBound<TMapping> {
+        &self.lower
+    }
+
+    /** The upper bound of the range, serialised as `lte` or `lt`. */
+    pub fn upper(&self) -> &RangeBound<TMapping> {
+        &self.upper
+    }
+
+    /**
+    Change the mapping of this range.
+
+    The new mapping must use the same kind of number.
+    */
+    pub fn remap<TNewMapping>(range: Range<TMapping>) -> Range<TNewMapping>
+    where
+        TNewMapping: RangeMapping<Value = TMapping::Value>,
+    {
+        Range::new(range.lower, range.upper)
+    }
+}
+
+impl<TMapping> RangeFieldType<TMapping> for Range<TMapping> where TMapping: RangeMapping {}
+
+impl<TMapping> Serialize for Range<TMapping>
+where
+    TMapping: RangeMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = [is_bounded(&self.lower), is_bounded(&self.upper)]
+            .iter()
+            .filter(|bounded| **bounded)
+            .count();
+
+        let mut state = try!(serializer.serialize_map(Some(len)));
+
+        match self.lower {
+            Bound::Included(ref value) => try!(state.serialize_entry("gte", value)),
+            Bound::Excluded(ref value) => try!(state.serialize_entry("gt", value)),
+            Bound::Unbounded => (),
+        }
+
+        match self.upper {
+            Bound::Included(ref value) => try!(state.serialize_entry("lte", value)),
+            Bound::Excluded(ref value) => try!(state.serialize_entry("lt", value)),
+            Bound::Unbounded => (),
+        }
+
+        state.end()
+    }
+}
+
+impl<'de, TMapping> Deserialize<'de> for Range<TMapping>
+where
+    TMapping: RangeMapping,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Range<TMapping>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RangeVisitor<TMapping> {
+            _t: PhantomData<TMapping>,
+        }
+
+        impl<'de, TMapping> Visitor<'de> for RangeVisitor<TMapping>
+        where
+            TMapping: RangeMapping,
+        {
+            type Value = Range<TMapping>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    "a json object with gte, gt, lte or lt bounds for {}",
+                    TMapping::Value::range_data_type()
+                )
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Range<TMapping>, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let (lower, upper) = try!(deserialize_bounds(map));
+
+                Ok(Range::new(lower, upper))
+            }
+        }
+
+        deserializer.deserialize_map(RangeVisitor::<TMapping> { _t: PhantomData })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::ops::Bound;
+
+    use prelude::*;
+
+    #[test]
+    fn serialise_elastic_range() {
+        let range: Range<DefaultRangeMapping<i32>> =
+            Range::new(Bound::Included(8000), Bound::Excluded(9000));
+
+        let ser = serde_json::to_string(&range).unwrap();
+
+        assert_eq!(r#"{"gte":8000,"lt":9000}"#, ser);
+    }
+
+    #[test]
+    fn serialise_elastic_range_unbounded() {
+        let range: Range<DefaultRangeMapping<f64>> =
+            Range::new(Bound::Excluded(18.5), Bound::Unbounded);
+
+        let ser = serde_json::to_string(&range).unwrap();
+
+        assert_eq!(r#"{"gt":18.5}"#, ser);
+
+        let range: Range<DefaultRangeMapping<i64>> = Range::new(Bound::Unbounded, Bound::Unbounded);
+
+        assert_eq!("{}", serde_json::to_string(&range).unwrap());
+    }
+
+    #[test]
+    fn deserialise_elastic_range() {
+        let range: Range<DefaultRangeMapping<i32>> =
+            serde_json::from_str(r#"{"lt":9000,"gte":8000}"#).unwrap();
+
+        assert_eq!(
+            Range::<DefaultRangeMapping<i32>>::new(Bound::Included(8000), Bound::Excluded(9000)),
+            range
+        );
+    }
+
+    #[test]
+    fn deserialise_elastic_range_unbounded() {
+        let range: Range<DefaultRangeMapping<i64>> =
+            serde_json::from_str(r#"{"gte":null,"lte":100}"#).unwrap();
+
+        assert_eq!(
+            Range::<DefaultRangeMapping<i64>>::new(Bound::Unbounded, Bound::Included(100)),
+            range
+        );
+    }
+
+    #[test]
+    fn deserialise_elastic_range_duplicate_bound() {
+        let range = serde_json::from_str::<Range<DefaultRangeMapping<i32>>>(r#"{"gte":1,"gt":1}"#);
+
+        assert!(range.is_err());
+    }
+
+    #[test]
+    fn deserialise_elastic_range_duplicate_null_bound() {
+        let range = serde_json::from_str::<Range<DefaultRangeMapping<i32>>>(r#"{"gte":null,"gt":1}"#);
+
+        assert!(range.is_err());
+
+        let range = serde_json::from_str::<Range<DefaultRangeMapping<i32>>>(r#"{"lt":null,"lte":null}"#);
+
+        assert!(range.is_err());
+    }
+
+    #[test]
+    fn deserialise_elastic_range_unknown_field() {
+        let range = serde_json::from_str::<Range<DefaultRangeMapping<i32>>>(r#"{"from":1}"#);
+
+        assert!(range.is_err());
+    }
+}
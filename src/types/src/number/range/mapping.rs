@@ -0,0 +1,273 @@
+/*! Mapping for the Elasticsearch numeric range types. */
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+
+/** A field that will be mapped as a numeric range. */
+pub trait RangeFieldType<TMapping> {}
+
+/**
+A number that can be used as the bound of a `Range`.
+
+The number decides which Elasticsearch range type the field is mapped as.
+*/
+pub trait RangeValue: Serialize + DeserializeOwned {
+    /** The Elasticsearch range type for this number, like `integer_range`. */
+    fn range_data_type() -> &'static str;
+}
+
+impl RangeValue for i32 {
+    fn range_data_type() -> &'static str {
+        "integer_range"
+    }
+}
+
+impl RangeValue for i64 {
+    fn range_data_type() -> &'static str {
+        "long_range"
+    }
+}
+
+impl RangeValue for f32 {
+    fn range_data_type() -> &'static str {
+        "float_range"
+    }
+}
+
+impl RangeValue for f64 {
+    fn range_data_type() -> &'static str {
+        "double_range"
+    }
+}
+
+/**
+The base requirements for mapping a numeric range type.
+
+# Examples
+
+Define a custom `RangeMapping`:
+
+```
+# #[macro_use]
+# extern crate elastic_types;
+# extern crate serde;
+# use elastic_types::prelude::*;
+#[derive(Default)]
+struct MyRangeMapping;
+impl RangeMapping for MyRangeMapping {
+    type Value = i64;
+
+    //Overload the mapping functions here
+    fn boost() -> Option<f32> {
+        Some(1.5)
+    }
+}
+# fn main() {}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use]
+# extern crate json_str;
+# #[macro_use]
+# extern crate elastic_types;
+# extern crate serde;
+# extern crate serde_json;
+# use elastic_types::prelude::*;
+# #[derive(Default)]
+# struct MyRangeMapping;
+# impl RangeMapping for MyRangeMapping {
+#     type Value = i64;
+#     fn boost() -> Option<f32> {
+#         Some(1.5)
+#     }
+# }
+# fn main() {
+# let mapping = elastic_types::derive::standalone_field_ser(MyRangeMapping).unwrap();
+# let json = json_str!(
+{
+    "type": "long_range",
+    "boost": 1.5
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+*/
+pub trait RangeMapping {
+    /**
+    The number bound to this mapping.
+
+    This decides the Elasticsearch range type, and is the type of the bounds of a `Range`.
+    */
+    type Value: RangeValue;
+
+    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
+    fn boost() -> Option<f32> {
+        None
+    }
+
+    /**
+    Try to convert strings to numbers and truncate fractions for integers.
+    Accepts `true` (default) and `false`.
+    */
+    fn coerce() -> Option<bool> {
+        None
+    }
+
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /**
+    Whether the field value should be stored and retrievable separately from the `_source` field.
+    Accepts `true` or `false` (default).
+    */
+    fn store() -> Option<bool> {
+        None
+    }
+}
+
+/** Default mapping for a numeric range. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultRangeMapping<TValue>
+where
+    TValue: RangeValue,
+{
+    _v: PhantomData<TValue>,
+}
+
+impl<TValue> RangeMapping for DefaultRangeMapping<TValue>
+where
+    TValue: RangeValue,
+{
+    type Value = TValue;
+}
+
+mod private {
+    use super::{
+        RangeFieldType,
+        RangeMapping,
+        RangeValue,
+    };
+    use private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::ser::SerializeStruct;
+    use serde::{
+        Serialize,
+        Serializer,
+    };
+
+    impl<TField, TMapping> FieldType<TMapping, RangePivot> for TField
+    where
+        TField: RangeFieldType<TMapping> + Serialize,
+        TMapping: RangeMapping,
+    {
+    }
+
+    #[derive(Default)]
+    pub struct RangePivot;
+
+    impl<TMapping> FieldMapping<RangePivot> for TMapping
+    where
+        TMapping: RangeMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, RangePivot>;
+
+        fn data_type() -> &'static str {
+            TMapping::Value::range_data_type()
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, RangePivot>
+    where
+        TMapping: FieldMapping<RangePivot> + RangeMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = try!(serializer.serialize_struct("mapping", 5));
+
+            try!(state.serialize_field("type", TMapping::data_type()));
+
+            ser_field!(state, "boost", TMapping::boost());
+            ser_field!(state, "coerce", TMapping::coerce());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "store", TMapping::store());
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use prelude::*;
+    use private::field;
+
+    #[derive(Default, Clone)]
+    pub struct MyRangeMapping;
+    impl RangeMapping for MyRangeMapping {
+        type Value = f64;
+
+        fn boost() -> Option<f32> {
+            Some(1.01)
+        }
+
+        fn coerce() -> Option<bool> {
+            Some(true)
+        }
+
+        fn index() -> Option<bool> {
+            Some(false)
+        }
+
+        fn store() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = vec![
+            serde_json::to_string(&field::serialize(DefaultRangeMapping::<i32>::default())).unwrap(),
+            serde_json::to_string(&field::serialize(DefaultRangeMapping::<i64>::default())).unwrap(),
+            serde_json::to_string(&field::serialize(DefaultRangeMapping::<f32>::default())).unwrap(),
+            serde_json::to_string(&field::serialize(DefaultRangeMapping::<f64>::default())).unwrap(),
+        ];
+
+        let expected = vec![
+            json_str!({ "type": "integer_range" }),
+            json_str!({ "type": "long_range" }),
+            json_str!({ "type": "float_range" }),
+            json_str!({ "type": "double_range" }),
+        ];
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_custom() {
+        let ser = serde_json::to_string(&field::serialize(MyRangeMapping)).unwrap();
+
+        let expected = json_str!({
+            "type": "double_range",
+            "boost": 1.01,
+            "coerce": true,
+            "index": false,
+            "store": true
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
@@ -0,0 +1,67 @@
+/*!
+Implementation of the Elasticsearch numeric range types.
+
+A numeric range stores a range of numbers in a single field, like an age bracket or a range of ports.
+The `Range<M>` type holds an optional lower and upper bound, each of which can be inclusive or exclusive.
+Either bound can be left open with `Bound::Unbounded`.
+
+The kind of number in the range decides the Elasticsearch type it's mapped as:
+
+Rust | Elasticsearch
+------ | ------------------
+`i32` | `integer_range`
+`i64` | `long_range`
+`f32` | `float_range`
+`f64` | `double_range`
+
+Ranges are serialised as an object with `gte`, `gt`, `lte` or `lt` keys.
+Unbounded sides are left out of the object.
+
+# Examples
+
+Map with a default `integer_range`:
+
+```
+# use elastic_types::prelude::*;
+struct MyType {
+    pub field: Range<DefaultRangeMapping<i32>>
+}
+```
+
+Map with a custom `double_range`:
+
+```
+# extern crate serde;
+# #[macro_use]
+# extern crate elastic_types;
+# fn main() {
+# use elastic_types::prelude::*;
+# #[derive(Default)]
+# struct MyRangeMapping;
+# impl RangeMapping for MyRangeMapping { type Value = f64; }
+struct MyType {
+    pub field: Range<MyRangeMapping>
+}
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/range.html)
+*/
+
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the numeric range types.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::impls::*;
+    pub use super::mapping::*;
+}
@@ -7,7 +7,7 @@ macro_rules! ser_field {
 }
 
 macro_rules! borrow_fn {
-    ($std_ty:ident) => {
+    ($std_ty:ty) => {
         fn borrow<T>(value: &T) -> &$std_ty
         where
             T: Borrow<$std_ty>,
@@ -18,7 +18,7 @@ macro_rules! borrow_fn {
 }
 
 macro_rules! impl_mapping_type {
-    ($std_ty:ident, $wrapper_ty:ident, $mapping_ty:ident) => {
+    ($std_ty:ty, $wrapper_ty:ident, $mapping_ty:ident) => {
         impl<M> ::private::field::StdField<$std_ty> for $wrapper_ty<M> where M: $mapping_ty {}
 
         impl<M> From<$std_ty> for $wrapper_ty<M>
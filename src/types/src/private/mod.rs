@@ -7,5 +7,6 @@ This module contains implementation details that support serialisation of mappab
 #[macro_use]
 pub mod macros;
 pub mod field;
+pub mod range;
 
 mod impls;
@@ -0,0 +1,60 @@
+/*!
+Implementation details shared by the range types.
+*/
+
+use serde::de::{
+    Deserialize,
+    Error,
+    MapAccess,
+};
+use std::ops::Bound;
+
+const FIELDS: &'static [&'static str] = &["gte", "gt", "lte", "lt"];
+
+/** Whether or not a bound has a value. */
+pub(crate) fn is_bounded<T>(bound: &Bound<T>) -> bool {
+    match *bound {
+        Bound::Unbounded => false,
+        _ => true,
+    }
+}
+
+/**
+Deserialise the lower and upper bounds of a range from a map with `gte`, `gt`, `lte` or `lt` keys.
+
+A `null` value leaves that side of the range open, but still counts as its bound,
+so it can't be followed by another key for the same side.
+*/
+pub(crate) fn deserialize_bounds<'de, A, T>(mut map: A) -> Result<(Bound<T>, Bound<T>), A::Error>
+where
+    A: MapAccess<'de>,
+    T: Deserialize<'de>,
+{
+    let (mut lower, mut seen_lower) = (Bound::Unbounded, false);
+    let (mut upper, mut seen_upper) = (Bound::Unbounded, false);
+
+    while let Some(key) = try!(map.next_key::<String>()) {
+        let (bound, seen) = match key.as_ref() {
+            "gte" | "gt" => (&mut lower, &mut seen_lower),
+            "lte" | "lt" => (&mut upper, &mut seen_upper),
+            _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+        };
+
+        if *seen {
+            return Err(A::Error::custom(format!(
+                "the range has more than one {} bound",
+                if key.starts_with("gt") { "lower" } else { "upper" }
+            )));
+        }
+
+        *seen = true;
+
+        *bound = match try!(map.next_value()) {
+            Some(value) if key.ends_with('e') => Bound::Included(value),
+            Some(value) => Bound::Excluded(value),
+            None => Bound::Unbounded,
+        };
+    }
+
+    Ok((lower, upper))
+}
@@ -89,6 +89,14 @@ pub trait KeywordMapping {
         None
     }
 
+    /**
+    Copy the value of this field into one or more other fields, which can then be queried as a single field.
+    The value is copied, not the terms that result from analysing it.
+    */
+    fn copy_to() -> Option<&'static [&'static str]> {
+        None
+    }
+
     /**
     Should the field be stored on disk in a column-stride fashion,
     so that it can later be used for sorting, aggregations, or scripting?
@@ -179,6 +187,14 @@ pub trait KeywordMapping {
         None
     }
 
+    /**
+    The normalizer to apply to values before they're indexed, like lowercasing for case-insensitive matching.
+    Defaults to `null`, which means values are indexed as-is.
+    */
+    fn normalizer() -> Option<&'static str> {
+        None
+    }
+
     /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
     fn norms() -> Option<bool> {
         None
@@ -215,6 +231,22 @@ pub trait KeywordMapping {
     fn similarity() -> Option<&'static str> {
         None
     }
+
+    /**
+    Should full text queries split the input on whitespace when building a query for this field?
+    Accepts `true` or `false` (default).
+    */
+    fn split_queries_on_whitespace() -> Option<bool> {
+        None
+    }
+
+    /**
+    Metadata about the field, like the unit of a metric.
+    Empty metadata isn't serialised.
+    */
+    fn meta() -> Option<BTreeMap<&'static str, &'static str>> {
+        None
+    }
 }
 
 /** Default mapping for `bool`. */
@@ -223,7 +255,7 @@ pub struct DefaultKeywordMapping;
 impl KeywordMapping for DefaultKeywordMapping {}
 
 /** A multi-field string mapping. */
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 pub struct KeywordFieldMapping {
     /**
     The analyzer which should be used for analyzed string fields,
@@ -231,6 +263,13 @@ pub struct KeywordFieldMapping {
     Defaults to the default index analyzer, or the `standard` analyzer.
     */
     pub analyzer: Option<&'static str>,
+    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
+    pub boost: Option<f32>,
+    /**
+    Copy the value of this field into one or more other fields, which can then be queried as a single field.
+    The value is copied, not the terms that result from analysing it.
+    */
+    pub copy_to: Option<&'static [&'static str]>,
     /**
     Should the field be stored on disk in a column-stride fashion,
     so that it can later be used for sorting, aggregations, or scripting?
@@ -244,6 +283,11 @@ pub struct KeywordFieldMapping {
     */
     pub eager_global_ordinals: Option<bool>,
     /**
+    Multi-fields of this sub-field, so the same string value can be indexed in even more ways.
+    These can declare their own `fields` to any depth.
+    */
+    pub fields: Option<BTreeMap<&'static str, StringField>>,
+    /**
     Whether or not the field value should be included in the `_all` field?
     Accepts true or false.
     Defaults to `false` if index is set to `no`, or if a parent object field sets `include_in_all` to false.
@@ -259,9 +303,19 @@ pub struct KeywordFieldMapping {
     pub index: Option<bool>,
     /** What information should be stored in the index, for search and highlighting purposes. Defaults to `Positions`. */
     pub index_options: Option<IndexOptions>,
+    /**
+    The normalizer to apply to values before they're indexed, like lowercasing for case-insensitive matching.
+    Defaults to `null`, which means values are indexed as-is.
+    */
+    pub normalizer: Option<&'static str>,
     /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
     pub norms: Option<bool>,
     /**
+    Accepts a `string` value which is substituted for any explicit null values.
+    Defaults to `null`, which means the field is treated as missing.
+    */
+    pub null_value: Option<&'static str>,
+    /**
     Whether the field value should be stored and retrievable separately from the `_source` field.
     Accepts `true` or `false` (default).
     */
@@ -276,6 +330,16 @@ pub struct KeywordFieldMapping {
     Defaults to `"classic"`, which uses TF/IDF.
     */
     pub similarity: Option<&'static str>,
+    /**
+    Should full text queries split the input on whitespace when building a query for this field?
+    Accepts `true` or `false` (default).
+    */
+    pub split_queries_on_whitespace: Option<bool>,
+    /**
+    Metadata about the field, like the unit of a metric.
+    Empty metadata isn't serialised.
+    */
+    pub meta: Option<BTreeMap<&'static str, &'static str>>,
 }
 
 impl Serialize for KeywordFieldMapping {
@@ -283,21 +347,36 @@ impl Serialize for KeywordFieldMapping {
     where
         S: Serializer,
     {
-        let mut state = try!(serializer.serialize_struct("mapping", 12));
+        let mut state = try!(serializer.serialize_struct("mapping", 19));
 
         try!(state.serialize_field("type", DefaultKeywordMapping::data_type()));
 
         ser_field!(state, "analyzer", self.analyzer);
+        ser_field!(state, "boost", self.boost);
+        ser_field!(state, "copy_to", self.copy_to);
         ser_field!(state, "doc_values", self.doc_values);
         ser_field!(state, "eager_global_ordinals", self.eager_global_ordinals);
+        ser_field!(state, "fields", self.fields.as_ref());
         ser_field!(state, "include_in_all", self.include_in_all);
         ser_field!(state, "ignore_above", self.ignore_above);
         ser_field!(state, "index", self.index);
         ser_field!(state, "index_options", self.index_options);
+        ser_field!(state, "normalizer", self.normalizer);
         ser_field!(state, "norms", self.norms);
+        ser_field!(state, "null_value", self.null_value);
         ser_field!(state, "store", self.store);
         ser_field!(state, "search_analyzer", self.search_analyzer);
         ser_field!(state, "similarity", self.similarity);
+        ser_field!(
+            state,
+            "split_queries_on_whitespace",
+            self.split_queries_on_whitespace
+        );
+        ser_field!(
+            state,
+            "meta",
+            self.meta.as_ref().filter(|meta| !meta.is_empty())
+        );
 
         state.end()
     }
@@ -349,12 +428,13 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = try!(serializer.serialize_struct("mapping", 15));
+            let mut state = try!(serializer.serialize_struct("mapping", 19));
 
             try!(state.serialize_field("type", TMapping::data_type()));
 
             ser_field!(state, "boost", TMapping::boost());
             ser_field!(state, "analyzer", TMapping::analyzer());
+            ser_field!(state, "copy_to", TMapping::copy_to());
             ser_field!(state, "doc_values", TMapping::doc_values());
             ser_field!(
                 state,
@@ -366,11 +446,22 @@ mod private {
             ser_field!(state, "ignore_above", TMapping::ignore_above());
             ser_field!(state, "index", TMapping::index());
             ser_field!(state, "index_options", TMapping::index_options());
+            ser_field!(state, "normalizer", TMapping::normalizer());
             ser_field!(state, "norms", TMapping::norms());
             ser_field!(state, "null_value", TMapping::null_value());
             ser_field!(state, "store", TMapping::store());
             ser_field!(state, "search_analyzer", TMapping::search_analyzer());
             ser_field!(state, "similarity", TMapping::similarity());
+            ser_field!(
+                state,
+                "split_queries_on_whitespace",
+                TMapping::split_queries_on_whitespace()
+            );
+            ser_field!(
+                state,
+                "meta",
+                TMapping::meta().filter(|meta| !meta.is_empty())
+            );
 
             state.end()
         }
@@ -5,6 +5,7 @@ use super::text::mapping::{
     TextFieldMapping,
     TextMapping,
 };
+use geo::mapping::Distance;
 use serde::ser::SerializeStruct;
 use serde::{
     Serialize,
@@ -12,15 +13,54 @@ use serde::{
 };
 use std::collections::BTreeMap;
 
-/** Default mapping for `String`. */
+/**
+The default `ignore_above` limit for the `keyword` sub-field of `DefaultStringMapping`.
+
+Values longer than this are still indexed as `text`, but aren't indexed in the `keyword` sub-field.
+*/
+pub const DEFAULT_KEYWORD_IGNORE_ABOVE: u32 = 256;
+
+/**
+Default mapping for `String`.
+
+Strings are mapped as `text` with a `keyword` sub-field that ignores values longer than [`DEFAULT_KEYWORD_IGNORE_ABOVE`][DEFAULT_KEYWORD_IGNORE_ABOVE].
+
+[DEFAULT_KEYWORD_IGNORE_ABOVE]: constant.DEFAULT_KEYWORD_IGNORE_ABOVE.html
+*/
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct DefaultStringMapping;
-impl TextMapping for DefaultStringMapping {
-    fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+
+impl DefaultStringMapping {
+    /**
+    Get the default sub-fields with a different `ignore_above` limit for the `keyword` sub-field.
+
+    Passing `None` removes the limit entirely.
+    This can be used to adjust the limit in a custom mapping without reimplementing the default `fields`.
+
+    # Examples
+
+    ```
+    # #[macro_use] extern crate elastic_types;
+    # extern crate serde;
+    # use std::collections::BTreeMap;
+    # use elastic_types::prelude::*;
+    #[derive(Default)]
+    struct MyStringMapping;
+    impl TextMapping for MyStringMapping {
+        fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+            DefaultStringMapping::with_keyword_ignore_above(Some(512))
+        }
+    }
+    # fn main() {}
+    ```
+    */
+    pub fn with_keyword_ignore_above(
+        ignore_above: Option<u32>,
+    ) -> Option<BTreeMap<&'static str, StringField>> {
         let mut fields = BTreeMap::new();
 
         let keyword = KeywordFieldMapping {
-            ignore_above: Some(256),
+            ignore_above: ignore_above,
             ..Default::default()
         };
 
@@ -30,6 +70,12 @@ impl TextMapping for DefaultStringMapping {
     }
 }
 
+impl TextMapping for DefaultStringMapping {
+    fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+        DefaultStringMapping::with_keyword_ignore_above(Some(DEFAULT_KEYWORD_IGNORE_ABOVE))
+    }
+}
+
 /** The `index_options` parameter controls what information is added to the inverted index, for search and highlighting purposes. */
 #[derive(Debug, Clone, Copy)]
 pub enum IndexOptions {
@@ -72,7 +118,7 @@ A string sub-field type.
 
 String types can have a number of alternative field representations for different purposes.
 */
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum StringField {
     /** A `token_count` sub field. */
     TokenCount(ElasticTokenCountFieldMapping),
@@ -90,10 +136,10 @@ impl Serialize for StringField {
         S: Serializer,
     {
         match *self {
-            StringField::TokenCount(m) => m.serialize(serializer),
-            StringField::Completion(m) => m.serialize(serializer),
-            StringField::Keyword(m) => m.serialize(serializer),
-            StringField::Text(m) => m.serialize(serializer),
+            StringField::TokenCount(ref m) => m.serialize(serializer),
+            StringField::Completion(ref m) => m.serialize(serializer),
+            StringField::Keyword(ref m) => m.serialize(serializer),
+            StringField::Text(ref m) => m.serialize(serializer),
         }
     }
 }
@@ -115,8 +161,8 @@ pub struct ElasticTokenCountFieldMapping {
     Accepts `true` (default) or `false`.
     */
     pub doc_values: Option<bool>,
-    /** Should the field be searchable? Accepts `not_analyzed` (default) and `no`. */
-    pub index: Option<IndexAnalysis>,
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
+    pub index: Option<bool>,
     /**
     Whether or not the field value should be included in the `_all` field?
     Accepts true or false.
@@ -158,7 +204,7 @@ impl Serialize for ElasticTokenCountFieldMapping {
 }
 
 /** A multi-field string mapping for a [completion suggester](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters-completion.html#search-suggesters-completion). */
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 pub struct ElasticCompletionFieldMapping {
     /**
     The analyzer which should be used for analyzed string fields,
@@ -193,6 +239,11 @@ pub struct ElasticCompletionFieldMapping {
     (Old name "max_input_len" is deprecated)
     */
     pub max_input_length: Option<u32>,
+    /**
+    Contexts that suggestions can be filtered or boosted by at search time,
+    like a category or a location.
+    */
+    pub contexts: Option<Vec<CompletionContext>>,
 }
 
 impl Serialize for ElasticCompletionFieldMapping {
@@ -200,7 +251,7 @@ impl Serialize for ElasticCompletionFieldMapping {
     where
         S: Serializer,
     {
-        let mut state = try!(serializer.serialize_struct("mapping", 7));
+        let mut state = try!(serializer.serialize_struct("mapping", 8));
 
         try!(state.serialize_field("type", "completion"));
 
@@ -214,12 +265,66 @@ impl Serialize for ElasticCompletionFieldMapping {
             self.preserve_position_increments
         );
         ser_field!(state, "max_input_length", self.max_input_length);
+        ser_field!(state, "contexts", self.contexts.as_ref());
+
+        state.end()
+    }
+}
+
+/** A [context](https://www.elastic.co/guide/en/elasticsearch/reference/current/suggester-context.html) for a completion suggester. */
+#[derive(Debug, Clone)]
+pub enum CompletionContext {
+    /** Suggestions are associated with one or more categories. */
+    Category {
+        /** The name of the context. */
+        name: &'static str,
+        /** A field to read the categories from, instead of giving them with each suggestion. */
+        path: Option<&'static str>,
+    },
+    /** Suggestions are associated with one or more geo points. */
+    Geo {
+        /** The name of the context. */
+        name: &'static str,
+        /** The precision of the geohash to index, defaults to `6`. */
+        precision: Option<Distance>,
+        /** A field to read the geo points from, instead of giving them with each suggestion. */
+        path: Option<&'static str>,
+    },
+}
+
+impl Serialize for CompletionContext {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = try!(serializer.serialize_struct("context", 4));
+
+        match *self {
+            CompletionContext::Category { name, path } => {
+                try!(state.serialize_field("name", name));
+                try!(state.serialize_field("type", "category"));
+
+                ser_field!(state, "path", path);
+            }
+            CompletionContext::Geo {
+                name,
+                precision,
+                path,
+            } => {
+                try!(state.serialize_field("name", name));
+                try!(state.serialize_field("type", "geo"));
+
+                ser_field!(state, "precision", precision);
+                ser_field!(state, "path", path);
+            }
+        }
 
         state.end()
     }
 }
 
 /** Should the field be searchable? Accepts `not_analyzed` (default) and `no`. */
+#[deprecated(note = "Elasticsearch 5.x and newer only accept a boolean `index` parameter, so use `Option<bool>` instead")]
 #[derive(Debug, Clone, Copy)]
 pub enum IndexAnalysis {
     /**
@@ -241,6 +346,7 @@ pub enum IndexAnalysis {
     No,
 }
 
+#[allow(deprecated)]
 impl Serialize for IndexAnalysis {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -304,6 +410,10 @@ mod tests {
             Some(1.3)
         }
 
+        fn copy_to() -> Option<&'static [&'static str]> {
+            Some(&["all_text", "title_text"])
+        }
+
         fn eager_global_ordinals() -> Option<bool> {
             Some(false)
         }
@@ -328,6 +438,17 @@ mod tests {
             Some(IndexOptions::Freqs)
         }
 
+        fn index_phrases() -> Option<bool> {
+            Some(true)
+        }
+
+        fn index_prefixes() -> Option<IndexPrefixes> {
+            Some(IndexPrefixes {
+                min_chars: Some(1),
+                max_chars: Some(10),
+            })
+        }
+
         fn norms() -> Option<bool> {
             Some(true)
         }
@@ -392,6 +513,10 @@ mod tests {
             Some(1.03)
         }
 
+        fn copy_to() -> Option<&'static [&'static str]> {
+            Some(&["all_text"])
+        }
+
         fn doc_values() -> Option<bool> {
             Some(true)
         }
@@ -416,6 +541,10 @@ mod tests {
             Some(IndexOptions::Docs)
         }
 
+        fn normalizer() -> Option<&'static str> {
+            Some("my_normalizer")
+        }
+
         fn norms() -> Option<bool> {
             Some(false)
         }
@@ -435,6 +564,10 @@ mod tests {
         fn similarity() -> Option<&'static str> {
             Some("classic")
         }
+
+        fn split_queries_on_whitespace() -> Option<bool> {
+            Some(false)
+        }
     }
 
     #[test]
@@ -454,6 +587,55 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn serialise_string_mapping_custom_keyword_ignore_above() {
+        #[derive(Default)]
+        struct MyStringMapping;
+        impl TextMapping for MyStringMapping {
+            fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+                DefaultStringMapping::with_keyword_ignore_above(Some(512))
+            }
+        }
+
+        let ser = serde_json::to_string(&field::serialize(MyStringMapping)).unwrap();
+
+        let expected = json_str!({
+            "type":"text",
+            "fields":{
+                "keyword":{
+                    "type":"keyword",
+                    "ignore_above":512
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_string_mapping_no_keyword_ignore_above() {
+        #[derive(Default)]
+        struct MyStringMapping;
+        impl TextMapping for MyStringMapping {
+            fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+                DefaultStringMapping::with_keyword_ignore_above(None)
+            }
+        }
+
+        let ser = serde_json::to_string(&field::serialize(MyStringMapping)).unwrap();
+
+        let expected = json_str!({
+            "type":"text",
+            "fields":{
+                "keyword":{
+                    "type":"keyword"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
     #[test]
     fn serialise_text_mapping_default() {
         let ser = serde_json::to_string(&field::serialize(DefaultTextMapping)).unwrap();
@@ -473,6 +655,7 @@ mod tests {
             "type":"text",
             "boost":1.3,
             "analyzer":"my_analyzer",
+            "copy_to":["all_text","title_text"],
             "eager_global_ordinals":false,
             "fielddata":true,
             "fielddata_frequency_filter":{
@@ -494,6 +677,11 @@ mod tests {
             "ignore_above":512,
             "index":false,
             "index_options":"freqs",
+            "index_phrases":true,
+            "index_prefixes":{
+                "min_chars":1,
+                "max_chars":10
+            },
             "norms":true,
             "position_increment_gap":1,
             "store":true,
@@ -525,6 +713,7 @@ mod tests {
             "type": "keyword",
             "boost": 1.03,
             "analyzer": "my_analyzer",
+            "copy_to": ["all_text"],
             "doc_values": true,
             "eager_global_ordinals": false,
             "fields": {
@@ -543,11 +732,13 @@ mod tests {
             "ignore_above": 256,
             "index": true,
             "index_options": "docs",
+            "normalizer": "my_normalizer",
             "norms": false,
             "null_value": "my string",
             "store": false,
             "search_analyzer": "my_analyzer",
-            "similarity": "classic"
+            "similarity": "classic",
+            "split_queries_on_whitespace": false
         });
 
         assert_eq!(expected, ser);
@@ -633,32 +824,44 @@ mod tests {
     fn serialise_mapping_keyword_field() {
         let mapping = StringField::Keyword(KeywordFieldMapping {
             analyzer: Some("my_analyzer"),
+            boost: Some(1.5),
+            copy_to: Some(&["all_text"]),
             doc_values: Some(true),
             eager_global_ordinals: Some(false),
+            fields: None,
             include_in_all: Some(true),
             ignore_above: Some(256),
             index: Some(false),
             index_options: Some(IndexOptions::Docs),
+            normalizer: Some("my_normalizer"),
             norms: Some(true),
+            null_value: Some("N/A"),
             store: Some(true),
             search_analyzer: Some("my_analyzer"),
             similarity: Some("my_analyzer"),
+            split_queries_on_whitespace: Some(true),
+            meta: None,
         });
         let ser = serde_json::to_string(&mapping).unwrap();
 
         let expected = json_str!({
             "type":"keyword",
             "analyzer":"my_analyzer",
+            "boost":1.5,
+            "copy_to":["all_text"],
             "doc_values":true,
             "eager_global_ordinals":false,
             "include_in_all":true,
             "ignore_above":256,
             "index":false,
             "index_options":"docs",
+            "normalizer":"my_normalizer",
             "norms":true,
+            "null_value":"N/A",
             "store":true,
             "search_analyzer":"my_analyzer",
-            "similarity":"my_analyzer"
+            "similarity":"my_analyzer",
+            "split_queries_on_whitespace":true
         });
 
         assert_eq!(expected, ser);
@@ -672,12 +875,19 @@ mod tests {
                 ..Default::default()
             }),
             analyzer: Some("my_analyzer"),
+            copy_to: Some(&["all_text", "title_text"]),
             eager_global_ordinals: Some(true),
             fielddata: Some(false),
+            fields: None,
             include_in_all: Some(false),
             ignore_above: Some(512),
             index: Some(true),
             index_options: Some(IndexOptions::Freqs),
+            index_phrases: Some(false),
+            index_prefixes: Some(IndexPrefixes {
+                min_chars: Some(2),
+                ..Default::default()
+            }),
             norms: Some(true),
             position_increment_gap: Some(1),
             store: Some(false),
@@ -685,12 +895,14 @@ mod tests {
             search_quote_analyzer: Some("my_analyzer"),
             similarity: Some("BM25"),
             term_vector: Some(TermVector::No),
+            meta: None,
         });
         let ser = serde_json::to_string(&mapping).unwrap();
 
         let expected = json_str!({
             "type":"text",
             "analyzer":"my_analyzer",
+            "copy_to":["all_text","title_text"],
             "eager_global_ordinals":true,
             "fielddata":false,
             "fielddata_frequency_filter":{
@@ -700,6 +912,10 @@ mod tests {
             "ignore_above":512,
             "index":true,
             "index_options":"freqs",
+            "index_phrases":false,
+            "index_prefixes":{
+                "min_chars":2
+            },
             "norms":true,
             "position_increment_gap":1,
             "store":false,
@@ -712,13 +928,110 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn serialise_mapping_text_field_builder() {
+        let mapping = StringField::Text(
+            TextFieldMapping::builder()
+                .analyzer("english")
+                .index_options(IndexOptions::Offsets)
+                .index_prefixes(IndexPrefixes {
+                    max_chars: Some(10),
+                    ..Default::default()
+                })
+                .store(true)
+                .build(),
+        );
+        let ser = serde_json::to_string(&mapping).unwrap();
+
+        let expected = json_str!({
+            "type":"text",
+            "analyzer":"english",
+            "index_options":"offsets",
+            "index_prefixes":{
+                "max_chars":10
+            },
+            "store":true
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_nested_fields() {
+        let mut completion_fields = BTreeMap::new();
+        completion_fields.insert(
+            "comp",
+            StringField::Completion(ElasticCompletionFieldMapping::default()),
+        );
+
+        let mut keyword_fields = BTreeMap::new();
+        keyword_fields.insert(
+            "raw",
+            StringField::Keyword(KeywordFieldMapping {
+                fields: Some(completion_fields),
+                ..Default::default()
+            }),
+        );
+
+        let mapping = StringField::Text(TextFieldMapping {
+            fields: Some(keyword_fields),
+            ..Default::default()
+        });
+        let ser = serde_json::to_string(&mapping).unwrap();
+
+        let expected = json_str!({
+            "type":"text",
+            "fields":{
+                "raw":{
+                    "type":"keyword",
+                    "fields":{
+                        "comp":{
+                            "type":"completion"
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_meta() {
+        let mut meta = BTreeMap::new();
+        meta.insert("unit", "percent");
+
+        let mapping = StringField::Keyword(KeywordFieldMapping {
+            meta: Some(meta),
+            ..Default::default()
+        });
+        let ser = serde_json::to_string(&mapping).unwrap();
+
+        let expected = json_str!({
+            "type":"keyword",
+            "meta":{
+                "unit":"percent"
+            }
+        });
+
+        assert_eq!(expected, ser);
+
+        let mapping = StringField::Text(TextFieldMapping {
+            meta: Some(BTreeMap::new()),
+            ..Default::default()
+        });
+        let ser = serde_json::to_string(&mapping).unwrap();
+
+        assert_eq!(r#"{"type":"text"}"#, ser);
+    }
+
     #[test]
     fn serialise_mapping_token_count_field() {
         let mapping = StringField::TokenCount(ElasticTokenCountFieldMapping {
             analyzer: Some("my_analyzer"),
             boost: Some(1.3),
             doc_values: Some(false),
-            index: Some(IndexAnalysis::No),
+            index: Some(false),
             include_in_all: Some(true),
             precision_step: Some(15),
             store: Some(true),
@@ -730,7 +1043,7 @@ mod tests {
             "analyzer": "my_analyzer",
             "boost": 1.3,
             "doc_values": false,
-            "index": "no",
+            "index": false,
             "include_in_all": true,
             "precision_step": 15,
             "store": true
@@ -748,6 +1061,7 @@ mod tests {
             preserve_separators: Some(false),
             preserve_position_increments: Some(true),
             max_input_length: Some(512),
+            contexts: None,
         });
         let ser = serde_json::to_string(&mapping).unwrap();
 
@@ -763,4 +1077,41 @@ mod tests {
 
         assert_eq!(expected, ser);
     }
+
+    #[test]
+    fn serialise_mapping_completion_field_contexts() {
+        let mapping = StringField::Completion(ElasticCompletionFieldMapping {
+            contexts: Some(vec![
+                CompletionContext::Category {
+                    name: "place_type",
+                    path: Some("cat"),
+                },
+                CompletionContext::Geo {
+                    name: "location",
+                    precision: Some(Distance(4.0, DistanceUnit::Kilometers)),
+                    path: None,
+                },
+            ]),
+            ..Default::default()
+        });
+        let ser = serde_json::to_string(&mapping).unwrap();
+
+        let expected = json_str!({
+            "type": "completion",
+            "contexts": [
+                {
+                    "name": "place_type",
+                    "type": "category",
+                    "path": "cat"
+                },
+                {
+                    "name": "location",
+                    "type": "geo",
+                    "precision": "4km"
+                }
+            ]
+        });
+
+        assert_eq!(expected, ser);
+    }
 }
@@ -89,6 +89,14 @@ pub trait TextMapping {
         None
     }
 
+    /**
+    Copy the value of this field into one or more other fields, which can then be queried as a single field.
+    The value is copied, not the terms that result from analysing it.
+    */
+    fn copy_to() -> Option<&'static [&'static str]> {
+        None
+    }
+
     /**
     Should global ordinals be loaded eagerly on refresh?
     Accepts `true` or `false` (default).
@@ -184,6 +192,22 @@ pub trait TextMapping {
         None
     }
 
+    /**
+    Index two-term word combinations into a separate field, so phrase queries run faster.
+    Accepts `true` or `false` (default).
+    */
+    fn index_phrases() -> Option<bool> {
+        None
+    }
+
+    /**
+    Index prefixes of terms into a separate field, so prefix queries run faster.
+    Defaults to `null`, which means prefixes aren't indexed.
+    */
+    fn index_prefixes() -> Option<IndexPrefixes> {
+        None
+    }
+
     /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
     fn norms() -> Option<bool> {
         None
@@ -238,6 +262,14 @@ pub trait TextMapping {
     fn term_vector() -> Option<TermVector> {
         None
     }
+
+    /**
+    Metadata about the field, like the unit of a metric.
+    Empty metadata isn't serialised.
+    */
+    fn meta() -> Option<BTreeMap<&'static str, &'static str>> {
+        None
+    }
 }
 
 /** Default mapping for `text`. */
@@ -301,8 +333,35 @@ impl Serialize for FieldDataFrequencyFilter {
     }
 }
 
+/**
+The range of prefix lengths to index for a `text` field.
+
+Elasticsearch checks that `min_chars` isn't greater than `max_chars` when the mapping is put.
+*/
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct IndexPrefixes {
+    /** The minimum prefix length to index. Must be greater than `0`, defaults to `2`. */
+    pub min_chars: Option<u32>,
+    /** The maximum prefix length to index. Must be less than `20`, defaults to `5`. */
+    pub max_chars: Option<u32>,
+}
+
+impl Serialize for IndexPrefixes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = try!(serializer.serialize_struct("mapping", 2));
+
+        ser_field!(state, "min_chars", self.min_chars);
+        ser_field!(state, "max_chars", self.max_chars);
+
+        state.end()
+    }
+}
+
 /** A multi-field string mapping. */
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 pub struct TextFieldMapping {
     /**
     The analyzer which should be used for analyzed string fields,
@@ -311,6 +370,11 @@ pub struct TextFieldMapping {
     */
     pub analyzer: Option<&'static str>,
     /**
+    Copy the value of this field into one or more other fields, which can then be queried as a single field.
+    The value is copied, not the terms that result from analysing it.
+    */
+    pub copy_to: Option<&'static [&'static str]>,
+    /**
     Should global ordinals be loaded eagerly on refresh?
     Accepts `true` or `false` (default).
     Enabling this is a good idea on fields that are frequently used for (significant) terms aggregations.
@@ -327,6 +391,11 @@ pub struct TextFieldMapping {
     */
     pub fielddata_frequency_filter: Option<FieldDataFrequencyFilter>,
     /**
+    Multi-fields of this sub-field, so the same string value can be indexed in even more ways.
+    These can declare their own `fields` to any depth.
+    */
+    pub fields: Option<BTreeMap<&'static str, StringField>>,
+    /**
     Whether or not the field value should be included in the `_all` field?
     Accepts true or false.
     Defaults to `false` if index is set to `no`, or if a parent object field sets `include_in_all` to false.
@@ -342,6 +411,16 @@ pub struct TextFieldMapping {
     pub index: Option<bool>,
     /** What information should be stored in the index, for search and highlighting purposes. Defaults to `Positions`. */
     pub index_options: Option<IndexOptions>,
+    /**
+    Index two-term word combinations into a separate field, so phrase queries run faster.
+    Accepts `true` or `false` (default).
+    */
+    pub index_phrases: Option<bool>,
+    /**
+    Index prefixes of terms into a separate field, so prefix queries run faster.
+    Defaults to `null`, which means prefixes aren't indexed.
+    */
+    pub index_prefixes: Option<IndexPrefixes>,
     /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
     pub norms: Option<bool>,
     /**
@@ -376,6 +455,11 @@ pub struct TextFieldMapping {
     Defaults to `No`.
     */
     pub term_vector: Option<TermVector>,
+    /**
+    Metadata about the field, like the unit of a metric.
+    Empty metadata isn't serialised.
+    */
+    pub meta: Option<BTreeMap<&'static str, &'static str>>,
 }
 
 impl Serialize for TextFieldMapping {
@@ -383,11 +467,12 @@ impl Serialize for TextFieldMapping {
     where
         S: Serializer,
     {
-        let mut state = try!(serializer.serialize_struct("mapping", 16));
+        let mut state = try!(serializer.serialize_struct("mapping", 21));
 
         try!(state.serialize_field("type", DefaultTextMapping::data_type()));
 
         ser_field!(state, "analyzer", self.analyzer);
+        ser_field!(state, "copy_to", self.copy_to);
         ser_field!(state, "eager_global_ordinals", self.eager_global_ordinals);
         ser_field!(state, "fielddata", self.fielddata);
         ser_field!(
@@ -395,10 +480,13 @@ impl Serialize for TextFieldMapping {
             "fielddata_frequency_filter",
             self.fielddata_frequency_filter
         );
+        ser_field!(state, "fields", self.fields.as_ref());
         ser_field!(state, "include_in_all", self.include_in_all);
         ser_field!(state, "ignore_above", self.ignore_above);
         ser_field!(state, "index", self.index);
         ser_field!(state, "index_options", self.index_options);
+        ser_field!(state, "index_phrases", self.index_phrases);
+        ser_field!(state, "index_prefixes", self.index_prefixes);
         ser_field!(state, "norms", self.norms);
         ser_field!(state, "position_increment_gap", self.position_increment_gap);
         ser_field!(state, "store", self.store);
@@ -406,11 +494,170 @@ impl Serialize for TextFieldMapping {
         ser_field!(state, "search_quote_analyzer", self.search_quote_analyzer);
         ser_field!(state, "similarity", self.similarity);
         ser_field!(state, "term_vector", self.term_vector);
+        ser_field!(
+            state,
+            "meta",
+            self.meta.as_ref().filter(|meta| !meta.is_empty())
+        );
 
         state.end()
     }
 }
 
+impl TextFieldMapping {
+    /**
+    Get a builder for a `TextFieldMapping`.
+
+    Any options that aren't set on the builder are left as `None`.
+
+    # Examples
+
+    ```
+    # use elastic_types::prelude::*;
+    let mapping = TextFieldMapping::builder()
+        .analyzer("english")
+        .store(true)
+        .build();
+    ```
+    */
+    pub fn builder() -> TextFieldMappingBuilder {
+        TextFieldMappingBuilder::default()
+    }
+}
+
+/** A builder for a `TextFieldMapping`. */
+#[derive(Debug, Default, Clone)]
+pub struct TextFieldMappingBuilder {
+    mapping: TextFieldMapping,
+}
+
+impl TextFieldMappingBuilder {
+    /** Set the `analyzer` of the mapping. */
+    pub fn analyzer(mut self, analyzer: &'static str) -> Self {
+        self.mapping.analyzer = Some(analyzer);
+        self
+    }
+
+    /** Set the `copy_to` of the mapping. */
+    pub fn copy_to(mut self, copy_to: &'static [&'static str]) -> Self {
+        self.mapping.copy_to = Some(copy_to);
+        self
+    }
+
+    /** Set the `eager_global_ordinals` of the mapping. */
+    pub fn eager_global_ordinals(mut self, eager_global_ordinals: bool) -> Self {
+        self.mapping.eager_global_ordinals = Some(eager_global_ordinals);
+        self
+    }
+
+    /** Set the `fielddata` of the mapping. */
+    pub fn fielddata(mut self, fielddata: bool) -> Self {
+        self.mapping.fielddata = Some(fielddata);
+        self
+    }
+
+    /** Set the `fielddata_frequency_filter` of the mapping. */
+    pub fn fielddata_frequency_filter(mut self, fielddata_frequency_filter: FieldDataFrequencyFilter) -> Self {
+        self.mapping.fielddata_frequency_filter = Some(fielddata_frequency_filter);
+        self
+    }
+
+    /** Set the `fields` of the mapping. */
+    pub fn fields(mut self, fields: BTreeMap<&'static str, StringField>) -> Self {
+        self.mapping.fields = Some(fields);
+        self
+    }
+
+    /** Set the `include_in_all` of the mapping. */
+    pub fn include_in_all(mut self, include_in_all: bool) -> Self {
+        self.mapping.include_in_all = Some(include_in_all);
+        self
+    }
+
+    /** Set the `ignore_above` of the mapping. */
+    pub fn ignore_above(mut self, ignore_above: u32) -> Self {
+        self.mapping.ignore_above = Some(ignore_above);
+        self
+    }
+
+    /** Set the `index` of the mapping. */
+    pub fn index(mut self, index: bool) -> Self {
+        self.mapping.index = Some(index);
+        self
+    }
+
+    /** Set the `index_options` of the mapping. */
+    pub fn index_options(mut self, index_options: IndexOptions) -> Self {
+        self.mapping.index_options = Some(index_options);
+        self
+    }
+
+    /** Set the `index_phrases` of the mapping. */
+    pub fn index_phrases(mut self, index_phrases: bool) -> Self {
+        self.mapping.index_phrases = Some(index_phrases);
+        self
+    }
+
+    /** Set the `index_prefixes` of the mapping. */
+    pub fn index_prefixes(mut self, index_prefixes: IndexPrefixes) -> Self {
+        self.mapping.index_prefixes = Some(index_prefixes);
+        self
+    }
+
+    /** Set the `norms` of the mapping. */
+    pub fn norms(mut self, norms: bool) -> Self {
+        self.mapping.norms = Some(norms);
+        self
+    }
+
+    /** Set the `position_increment_gap` of the mapping. */
+    pub fn position_increment_gap(mut self, position_increment_gap: u32) -> Self {
+        self.mapping.position_increment_gap = Some(position_increment_gap);
+        self
+    }
+
+    /** Set the `store` of the mapping. */
+    pub fn store(mut self, store: bool) -> Self {
+        self.mapping.store = Some(store);
+        self
+    }
+
+    /** Set the `search_analyzer` of the mapping. */
+    pub fn search_analyzer(mut self, search_analyzer: &'static str) -> Self {
+        self.mapping.search_analyzer = Some(search_analyzer);
+        self
+    }
+
+    /** Set the `search_quote_analyzer` of the mapping. */
+    pub fn search_quote_analyzer(mut self, search_quote_analyzer: &'static str) -> Self {
+        self.mapping.search_quote_analyzer = Some(search_quote_analyzer);
+        self
+    }
+
+    /** Set the `similarity` of the mapping. */
+    pub fn similarity(mut self, similarity: &'static str) -> Self {
+        self.mapping.similarity = Some(similarity);
+        self
+    }
+
+    /** Set the `term_vector` of the mapping. */
+    pub fn term_vector(mut self, term_vector: TermVector) -> Self {
+        self.mapping.term_vector = Some(term_vector);
+        self
+    }
+
+    /** Set the `meta` of the mapping. */
+    pub fn meta(mut self, meta: BTreeMap<&'static str, &'static str>) -> Self {
+        self.mapping.meta = Some(meta);
+        self
+    }
+
+    /** Build the `TextFieldMapping`. */
+    pub fn build(self) -> TextFieldMapping {
+        self.mapping
+    }
+}
+
 mod private {
     use super::{
         TextFieldType,
@@ -457,12 +704,13 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = try!(serializer.serialize_struct("mapping", 18));
+            let mut state = try!(serializer.serialize_struct("mapping", 22));
 
             try!(state.serialize_field("type", TMapping::data_type()));
 
             ser_field!(state, "boost", TMapping::boost());
             ser_field!(state, "analyzer", TMapping::analyzer());
+            ser_field!(state, "copy_to", TMapping::copy_to());
             ser_field!(
                 state,
                 "eager_global_ordinals",
@@ -479,6 +727,8 @@ mod private {
             ser_field!(state, "ignore_above", TMapping::ignore_above());
             ser_field!(state, "index", TMapping::index());
             ser_field!(state, "index_options", TMapping::index_options());
+            ser_field!(state, "index_phrases", TMapping::index_phrases());
+            ser_field!(state, "index_prefixes", TMapping::index_prefixes());
             ser_field!(state, "norms", TMapping::norms());
             ser_field!(
                 state,
@@ -494,6 +744,11 @@ mod private {
             );
             ser_field!(state, "similarity", TMapping::similarity());
             ser_field!(state, "term_vector", TMapping::term_vector());
+            ser_field!(
+                state,
+                "meta",
+                TMapping::meta().filter(|meta| !meta.is_empty())
+            );
 
             state.end()
         }
@@ -46,6 +46,11 @@ pub fn derive_date_format(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 
             expanded.to_string().parse().unwrap()
         }
-        Err(e) => panic!("{}", e),
+        // Report bad formats as a regular compiler error instead of a panic in the derive
+        Err(e) => {
+            let msg = e.to_string();
+
+            quote!(compile_error!(#msg);).to_string().parse().unwrap()
+        }
     }
 }
@@ -16,6 +16,9 @@ The input must satisfy the following rules:
 
 - It must be a unit struct.
 - It must have an `#[elastic(date_format="<value>")]` attribute.
+
+Multiple formats can be given as a `||`-joined string or as repeated `date_format` attributes.
+Dates are parsed with each format in turn and formatted with the first one.
 */
 pub fn expand_derive(
     crate_root: Tokens,
@@ -30,14 +33,23 @@ pub fn expand_derive(
         _ => Err(DeriveDateFormatError::InvalidInput),
     }?;
 
-    let format = get_format_from_attr(input).ok_or(DeriveDateFormatError::MissingFormat)?;
+    let formats = get_formats_from_attr(input);
+
+    if formats.is_empty() {
+        return Err(DeriveDateFormatError::MissingFormat);
+    }
 
-    let name = get_name_from_attr(input).unwrap_or_else(|| format.clone());
+    let name = get_name_from_attr(input).unwrap_or_else(|| formats.join("||"));
 
-    let tokens: Vec<Tokens> = parse::to_tokens(&format)?
-        .into_iter()
-        .map(|t| t.into_tokens(&crate_root))
-        .collect();
+    let mut tokens = Vec::with_capacity(formats.len());
+    for format in &formats {
+        let format_tokens: Vec<Tokens> = parse::to_tokens(format)?
+            .into_iter()
+            .map(|t| t.into_tokens(&crate_root))
+            .collect();
+
+        tokens.push(format_tokens);
+    }
 
     let derived = impl_date_format(crate_root, input, &name, &tokens);
 
@@ -49,17 +61,18 @@ fn impl_date_format(
     crate_root: Tokens,
     item: &syn::MacroInput,
     name: &str,
-    format: &[Tokens],
+    formats: &[Vec<Tokens>],
 ) -> Tokens {
     let ty = &item.ident;
 
-    let parse_fn = quote!(
-        fn parse(date: &str) -> ::std::result::Result<#crate_root::derive::DateValue, #crate_root::derive::ParseError> {
-            let fmt = vec![ #(#format),* ];
+    // Dates are always formatted using the first format
+    let format = &formats[0];
 
-            #crate_root::derive::parse_from_tokens(date, fmt)
-        }
-    );
+    let (parse_fn, parse_with_default_time_fn) = if formats.len() == 1 {
+        impl_parse_single(&crate_root, format)
+    } else {
+        impl_parse_any(&crate_root, formats)
+    };
 
     let format_fn = quote!(
         fn format<'a>(date: &'a #crate_root::derive::DateValue) -> #crate_root::derive::FormattedDate<'a> {
@@ -79,6 +92,8 @@ fn impl_date_format(
         impl #crate_root::derive::DateFormat for #ty {
             #parse_fn
 
+            #parse_with_default_time_fn
+
             #format_fn
 
             #name_fn
@@ -86,16 +101,65 @@ fn impl_date_format(
     )
 }
 
-// Get the format string supplied by an #[elastic()] attribute
-fn get_format_from_attr<'a>(item: &'a syn::MacroInput) -> Option<String> {
-    let val = get_elastic_meta_items(&item.attrs);
+// Parse dates with a single format
+fn impl_parse_single(crate_root: &Tokens, format: &[Tokens]) -> (Tokens, Tokens) {
+    let parse_fn = quote!(
+        fn parse(date: &str) -> ::std::result::Result<#crate_root::derive::DateValue, #crate_root::derive::ParseError> {
+            let fmt = vec![ #(#format),* ];
 
-    let val = val
+            #crate_root::derive::parse_from_tokens(date, fmt)
+        }
+    );
+
+    let parse_with_default_time_fn = quote!(
+        fn parse_with_default_time(date: &str, time: #crate_root::derive::NaiveTime) -> ::std::result::Result<#crate_root::derive::DateValue, #crate_root::derive::ParseError> {
+            let fmt = vec![ #(#format),* ];
+
+            #crate_root::derive::parse_from_tokens_with_default_time(date, fmt, time)
+        }
+    );
+
+    (parse_fn, parse_with_default_time_fn)
+}
+
+// Parse dates with each format in turn
+fn impl_parse_any(crate_root: &Tokens, formats: &[Vec<Tokens>]) -> (Tokens, Tokens) {
+    let formats: Vec<Tokens> = formats
         .iter()
-        .filter_map(|meta| expect_name_value("date_format", &meta))
-        .next();
+        .map(|format| quote!(vec![ #(#format),* ]))
+        .collect();
 
-    val.and_then(|v| get_str_from_lit(v).ok().map(Into::into))
+    let parse_fn = quote!(
+        fn parse(date: &str) -> ::std::result::Result<#crate_root::derive::DateValue, #crate_root::derive::ParseError> {
+            let fmts = vec![ #(#formats),* ];
+
+            #crate_root::derive::parse_from_any_tokens(date, fmts)
+        }
+    );
+
+    let parse_with_default_time_fn = quote!(
+        fn parse_with_default_time(date: &str, time: #crate_root::derive::NaiveTime) -> ::std::result::Result<#crate_root::derive::DateValue, #crate_root::derive::ParseError> {
+            let fmts = vec![ #(#formats),* ];
+
+            #crate_root::derive::parse_from_any_tokens_with_default_time(date, fmts, time)
+        }
+    );
+
+    (parse_fn, parse_with_default_time_fn)
+}
+
+// Get the format strings supplied by any #[elastic()] attributes, splitting `||`-joined formats
+fn get_formats_from_attr<'a>(item: &'a syn::MacroInput) -> Vec<String> {
+    let val = get_elastic_meta_items(&item.attrs);
+
+    val.iter()
+        .filter_map(|meta| expect_name_value("date_format", &meta))
+        .filter_map(|v| get_str_from_lit(v).ok())
+        .flat_map(|v| v.split("||"))
+        .map(|format| format.trim())
+        .filter(|format| !format.is_empty())
+        .map(Into::into)
+        .collect()
 }
 
 // Get the name string supplied by an #[elastic()] attribute
@@ -139,6 +203,12 @@ impl<'a> parse::DateFormatToken<'a> {
             Millisecond => {
                 quote!(#crate_root::derive::Item::Fixed(#crate_root::derive::Fixed::Nanosecond3))
             }
+            Microsecond => {
+                quote!(#crate_root::derive::Item::Fixed(#crate_root::derive::Fixed::Nanosecond6))
+            }
+            Nanosecond => {
+                quote!(#crate_root::derive::Item::Fixed(#crate_root::derive::Fixed::Nanosecond9))
+            }
             Utc => quote!(#crate_root::derive::Item::Literal("Z")),
             Delim(s) => quote!(#crate_root::derive::Item::Literal(#s)),
             Escaped(s) => quote!(#crate_root::derive::Item::Literal(#s)),
@@ -156,7 +226,7 @@ quick_error! {
             display("missing date format. Add a `#[elastic(date_format=\"<format here>\")]`")
         }
         InvalidFormat(err: parse::Error) {
-            display("error parsing date format")
+            display("error parsing date format: {}", err)
             from()
         }
     }
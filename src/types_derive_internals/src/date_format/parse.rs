@@ -1,7 +1,20 @@
-use nom::simple_errors::Err as NomError;
+use nom::IResult;
 use std::str;
 
-pub type Error = NomError;
+quick_error! {
+    #[derive(Debug)]
+    pub enum Error {
+        UnrecognisedToken(format: String, position: usize, token: String) {
+            display("unrecognised token `{}` at position {} in date format `{}`", token, position, format)
+        }
+        UnterminatedEscape(format: String, position: usize, escaped: String) {
+            display("unterminated escape `{}` ends at position {} in date format `{}`", escaped, position, format)
+        }
+        Empty {
+            display("the date format is empty")
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DateFormatToken<'a> {
@@ -13,34 +26,67 @@ pub enum DateFormatToken<'a> {
     Minute,
     Second,
     Millisecond,
+    Microsecond,
+    Nanosecond,
     Utc,
     Delim(&'a str),
     Escaped(&'a str),
 }
 
+/**
+Parse a date format into tokens.
+
+The whole format must be recognised, so any unsupported characters are returned as an error
+instead of being silently dropped.
+*/
 pub fn to_tokens<'a>(input: &'a str) -> Result<Vec<DateFormatToken<'a>>, Error> {
-    format(input.as_bytes()).to_result()
-}
+    if input.is_empty() {
+        return Err(Error::Empty);
+    }
 
-named!(format(&[u8]) -> Vec<DateFormatToken>,
-    complete!(tokens)
-);
+    let mut tokens = Vec::new();
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        match token(rest.as_bytes()) {
+            IResult::Done(remaining, token) => {
+                tokens.push(token);
+                rest = &rest[rest.len() - remaining.len()..];
+            }
+            IResult::Incomplete(_) if rest.starts_with('\'') => {
+                return Err(Error::UnterminatedEscape(
+                    input.to_owned(),
+                    input.len(),
+                    rest.to_owned(),
+                ));
+            }
+            _ => {
+                let position = input.len() - rest.len();
+                let token = rest.chars().next().map(|c| c.to_string()).unwrap_or_default();
 
-named!(tokens(&[u8]) -> Vec<DateFormatToken>,
-    many1!(
-        alt!(
-            year |
-            month |
-            day_of_month |
-            day_of_year |
-            hour |
-            minute |
-            second |
-            millisecond |
-            utc |
-            escaped |
-            delim
-        )
+                return Err(Error::UnrecognisedToken(input.to_owned(), position, token));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+named!(token(&[u8]) -> DateFormatToken,
+    alt!(
+        year |
+        month |
+        day_of_month |
+        day_of_year |
+        hour |
+        minute |
+        second |
+        complete!(nanosecond) |
+        complete!(microsecond) |
+        complete!(millisecond) |
+        utc |
+        escaped |
+        delim
     )
 );
 
@@ -109,6 +155,24 @@ named!(millisecond(&[u8]) -> DateFormatToken,
     )
 );
 
+/** Parse `.SSSSSS` as a 6 digit microsecond of second. */
+named!(microsecond(&[u8]) -> DateFormatToken,
+    do_parse!(
+        tag!(".") >>
+        count!(char!('S'), 6) >>
+        (DateFormatToken::Microsecond)
+    )
+);
+
+/** Parse `.SSSSSSSSS` as a 9 digit nanosecond of second. */
+named!(nanosecond(&[u8]) -> DateFormatToken,
+    do_parse!(
+        tag!(".") >>
+        count!(char!('S'), 9) >>
+        (DateFormatToken::Nanosecond)
+    )
+);
+
 /** Parse `Z` as a Utc timezone. */
 named!(utc(&[u8]) -> DateFormatToken,
     do_parse!(
@@ -142,21 +206,64 @@ mod tests {
     use self::DateFormatToken::*;
     use super::*;
 
-    fn assert_parse(i: &[u8], expected: Vec<DateFormatToken>) {
-        let (_, result) = format(i).unwrap();
+    fn assert_parse(i: &str, expected: Vec<DateFormatToken>) {
+        let result = to_tokens(i).unwrap();
 
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn to_tokens_unrecognised() {
+        match to_tokens("yyyy-MM-dd'T'HH:mm:ss.SSSX") {
+            Err(Error::UnrecognisedToken(_, position, token)) => {
+                assert_eq!((25, "X".to_owned()), (position, token))
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+
+        match to_tokens("qyyyy") {
+            Err(Error::UnrecognisedToken(_, position, token)) => {
+                assert_eq!((0, "q".to_owned()), (position, token))
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn to_tokens_unterminated_escape() {
+        match to_tokens("yyyyMMdd'T") {
+            Err(Error::UnterminatedEscape(_, position, escaped)) => {
+                assert_eq!((10, "'T".to_owned()), (position, escaped))
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn to_tokens_incomplete_token() {
+        match to_tokens("yyyyM") {
+            Err(Error::UnrecognisedToken(_, position, token)) => {
+                assert_eq!((4, "M".to_owned()), (position, token))
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn to_tokens_valid() {
+        assert!(to_tokens("yyyy/MM/dd HH:mm:ss").is_ok());
+        assert!(to_tokens("yyyyMMdd").is_ok());
+    }
+
     #[test]
     fn parse_basic_date() {
-        assert_parse(b"yyyyMMdd", vec![Year, Month, DayOfMonth]);
+        assert_parse("yyyyMMdd", vec![Year, Month, DayOfMonth]);
     }
 
     #[test]
     fn parse_basic_date_time() {
         assert_parse(
-            b"yyyyMMdd'T'HHmmss.SSSZ",
+            "yyyyMMdd'T'HHmmss.SSSZ",
             vec![
                 Year,
                 Month,
@@ -174,7 +281,7 @@ mod tests {
     #[test]
     fn parse_basic_date_time_no_millis() {
         assert_parse(
-            b"yyyyMMdd'T'HHmmssZ",
+            "yyyyMMdd'T'HHmmssZ",
             vec![
                 Year,
                 Month,
@@ -188,15 +295,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_fractional_seconds() {
+        assert_parse("ss.SSS", vec![Second, Millisecond]);
+        assert_parse("ss.SSSSSS", vec![Second, Microsecond]);
+        assert_parse("ss.SSSSSSSSS", vec![Second, Nanosecond]);
+    }
+
     #[test]
     fn parse_basic_ordinal_date() {
-        assert_parse(b"yyyyDDD", vec![Year, DayOfYear]);
+        assert_parse("yyyyDDD", vec![Year, DayOfYear]);
     }
 
     #[test]
     fn parse_date_hour_minute_second_millis() {
         assert_parse(
-            b"yyyy-MM-dd'T'HH:mm:ss.SSS",
+            "yyyy-MM-dd'T'HH:mm:ss.SSS",
             vec![
                 Year,
                 Delim("-"),